@@ -0,0 +1,10 @@
+// just re-export the contained modules here
+
+/// Function selector, and event topic computation
+pub mod signature;
+
+/// Decoding of ABI encoded data
+pub mod decode;
+
+/// Encoding of values, and function calls into ABI encoded data
+pub mod encode;
@@ -0,0 +1,286 @@
+use crate::prelude::*;
+use crate::types::evm_types::{EvmContractABIItem, EvmContractABIItemKind};
+use crate::types::abi_types::*;
+use crate::abi::signature::{function_selector, parse_signature, EvmSignatureDatabase};
+
+/// Decode hexadecimal string with or without `0x` prefixed into bytes.
+///
+/// # Arguments
+/// * `text` - hexadecimal string
+pub fn decode_hex(text: &str) -> Result<Vec<u8>, EvmError> {
+    let text = text.trim();
+    let text = text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")).unwrap_or(text);
+    hex::decode(text).map_err(|e| EvmError::ErrorAbiCoding(format!("invalid hex string; err={}", e)))
+}
+
+/// Decode ABI encoded data according to the specified types as if they are
+/// members of a tuple.
+///
+/// # Arguments
+/// * `types` - types of encoded values
+/// * `data` - ABI encoded data
+pub fn decode(types: &[EvmAbiParamType], data: &[u8]) -> Result<Vec<EvmAbiValue>, EvmError> {
+    decode_tuple(types, data, 0)
+}
+
+/// Decode input of transaction calling a function of contract whose ABI is
+/// `abi` by matching its 4-byte selector.
+///
+/// Return `Ok(None)` if input is too short to contain a selector, or there is
+/// no function in `abi` matching the selector. Function whose signature cannot
+/// be built e.g. unsupported type is skipped. Only failure in decoding input
+/// against the matched function is returned as `Err`.
+///
+/// # Arguments
+/// * `abi` - contract ABI
+/// * `input` - transaction's input in hexadecimal string
+pub fn decode_function_input(abi: &[EvmContractABIItem], input: &str) -> Result<Option<EvmAbiDecodedCall>, EvmError> {
+    let data = decode_hex(input)?;
+    if data.len() < 4 {
+        return Ok(None);
+    }
+
+    for item in abi.iter().filter(|item| item.r#type == EvmContractABIItemKind::Function) {
+        // item with unsupported type cannot be the one being called, unless
+        // none of items matches
+        let signature = match item.signature() {
+            Ok(signature) => signature,
+            Err(_) => continue,
+        };
+        if function_selector(&signature)[..] != data[..4] {
+            continue;
+        }
+
+        let params = item.inputs.clone().unwrap_or_default();
+        let types = EvmAbiParamType::from_abi_params(&params)?;
+        let values = decode(&types, &data[4..])?;
+
+        let mut selector = [0u8; 4];
+        selector.copy_from_slice(&data[..4]);
+
+        return Ok(Some(EvmAbiDecodedCall {
+            name: item.name.clone().unwrap_or_default(),
+            signature,
+            selector,
+            inputs: params.into_iter().zip(types).zip(values)
+                .map(|((param, param_type), value)| EvmAbiNamedValue { name: param.name, param_type, value })
+                .collect(),
+        }));
+    }
+
+    Ok(None)
+}
+
+/// Decode input of transaction calling a function of contract whose ABI is
+/// not known e.g. un-verified contract. Its 4-byte selector is resolved into
+/// candidate signatures from `database`, then the first candidate which can
+/// decode the input is used. Parameter names are empty as signature doesn't
+/// carry them.
+///
+/// Return `Ok(None)` if input is too short to contain a selector, or none of
+/// candidates can decode the input.
+///
+/// # Arguments
+/// * `database` - signature database to resolve the selector from
+/// * `input` - transaction's input in hexadecimal string
+pub fn decode_function_input_by_signatures(database: &EvmSignatureDatabase, input: &str) -> Result<Option<EvmAbiDecodedCall>, EvmError> {
+    let data = decode_hex(input)?;
+    if data.len() < 4 {
+        return Ok(None);
+    }
+
+    let mut selector = [0u8; 4];
+    selector.copy_from_slice(&data[..4]);
+
+    for signature in database.lookup_function(&selector) {
+        let (name, types) = parse_signature(signature)?;
+        if let Ok(values) = decode(&types, &data[4..]) {
+            return Ok(Some(EvmAbiDecodedCall {
+                name,
+                signature: signature.clone(),
+                selector,
+                inputs: types.into_iter().zip(values)
+                    .map(|(param_type, value)| EvmAbiNamedValue { name: String::new(), param_type, value })
+                    .collect(),
+            }));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Decode return data of calling a function according to outputs of its ABI
+/// item.
+///
+/// # Arguments
+/// * `item` - ABI item of the function
+/// * `data` - return data
+pub fn decode_function_output(item: &EvmContractABIItem, data: &[u8]) -> Result<Vec<EvmAbiNamedValue>, EvmError> {
+    let params = item.outputs.clone().unwrap_or_default();
+    let types = EvmAbiParamType::from_abi_params(&params)?;
+    let values = decode(&types, data)?;
+    Ok(params.into_iter().zip(types).zip(values)
+        .map(|((param, param_type), value)| EvmAbiNamedValue { name: param.name, param_type, value })
+        .collect())
+}
+
+/// Decode ABI encoded constructor's arguments according to the constructor
+/// of contract ABI.
+///
+/// Return `Err` if the length of `args` is not a multiple of 32 bytes, or
+/// there are arguments but contract ABI has no constructor.
+///
+/// # Arguments
+/// * `abi` - contract ABI
+/// * `args` - ABI encoded constructor's arguments in hexadecimal string
+pub fn decode_constructor_arguments(abi: &[EvmContractABIItem], args: &str) -> Result<Vec<EvmAbiNamedValue>, EvmError> {
+    let data = decode_hex(args)?;
+    if data.len() % 32 != 0 {
+        return Err(EvmError::ErrorAbiCoding(format!("length of constructor's arguments {} bytes is not a multiple of 32", data.len())));
+    }
+
+    let params = match abi.iter().find(|item| item.r#type == EvmContractABIItemKind::Constructor) {
+        Some(item) => item.inputs.clone().unwrap_or_default(),
+        None if data.is_empty() => Vec::new(),
+        None => return Err(EvmError::ErrorAbiCoding("constructor's arguments are present, but ABI has no constructor".to_owned())),
+    };
+
+    let types = EvmAbiParamType::from_abi_params(&params)?;
+    let values = decode(&types, &data)?;
+    Ok(params.into_iter().zip(types).zip(values)
+        .map(|((param, param_type), value)| EvmAbiNamedValue { name: param.name, param_type, value })
+        .collect())
+}
+
+/// Decode members of tuple whose encoding starts at `base`.
+fn decode_tuple(types: &[EvmAbiParamType], data: &[u8], base: usize) -> Result<Vec<EvmAbiValue>, EvmError> {
+    let mut res = Vec::with_capacity(types.len());
+    let mut head = base;
+
+    for t in types {
+        if t.is_dynamic() {
+            let offset = read_usize(data, head)?;
+            let position = base.checked_add(offset).ok_or_else(|| EvmError::ErrorAbiCoding(format!("offset {} is out of range", offset)))?;
+            res.push(decode_param(t, data, position)?);
+        }
+        else {
+            res.push(decode_param(t, data, head)?);
+        }
+        head += t.head_size();
+    }
+
+    Ok(res)
+}
+
+/// Decode a single value whose encoding starts at `position`.
+fn decode_param(t: &EvmAbiParamType, data: &[u8], position: usize) -> Result<EvmAbiValue, EvmError> {
+    match t {
+        EvmAbiParamType::Address => {
+            let word = read_word(data, position)?;
+            check_zero(&word[..12], t)?;
+            Ok(EvmAbiValue::Address(H160::from_slice(&word[12..])))
+        },
+        EvmAbiParamType::Bool => {
+            let word = read_word(data, position)?;
+            check_zero(&word[..31], t)?;
+            match word[31] {
+                0 => Ok(EvmAbiValue::Bool(false)),
+                1 => Ok(EvmAbiValue::Bool(true)),
+                _ => Err(EvmError::ErrorAbiCoding("invalid value for bool".to_owned())),
+            }
+        },
+        EvmAbiParamType::Function => {
+            let word = read_word(data, position)?;
+            check_zero(&word[24..], t)?;
+            let mut selector = [0u8; 4];
+            selector.copy_from_slice(&word[20..24]);
+            Ok(EvmAbiValue::Function(H160::from_slice(&word[..20]), selector))
+        },
+        EvmAbiParamType::Uint(bits) => {
+            let value = U256::from_big_endian(read_word(data, position)?);
+            if value.bits() > *bits {
+                return Err(EvmError::ErrorAbiCoding(format!("value is out of range for {}", t)));
+            }
+            Ok(EvmAbiValue::Uint(value))
+        },
+        EvmAbiParamType::Int(bits) => {
+            let value = I256(U256::from_big_endian(read_word(data, position)?));
+            // value must be sign-extended from its number of bits
+            let limit = U256::one() << (bits - 1);
+            let is_in_range = if value.is_negative() { value.abs() <= limit } else { value.0 < limit };
+            if !is_in_range {
+                return Err(EvmError::ErrorAbiCoding(format!("value is out of range for {}", t)));
+            }
+            Ok(EvmAbiValue::Int(value))
+        },
+        EvmAbiParamType::FixedBytes(size) => {
+            let word = read_word(data, position)?;
+            check_zero(&word[*size..], t)?;
+            Ok(EvmAbiValue::FixedBytes(word[..*size].to_vec()))
+        },
+        EvmAbiParamType::Bytes => {
+            Ok(EvmAbiValue::Bytes(read_bytes(data, position)?.to_vec()))
+        },
+        EvmAbiParamType::String => {
+            match String::from_utf8(read_bytes(data, position)?.to_vec()) {
+                Ok(text) => Ok(EvmAbiValue::String(text)),
+                Err(e) => Err(EvmError::ErrorAbiCoding(format!("invalid UTF-8 string; err={}", e))),
+            }
+        },
+        EvmAbiParamType::Array(inner) => {
+            let length = read_usize(data, position)?;
+            // each element occupies at least one word, so this guards against
+            // bogus length before allocating
+            if length > data.len().saturating_sub(position + 32) / 32 {
+                return Err(EvmError::ErrorAbiCoding(format!("array length {} is out of range", length)));
+            }
+            let types = vec![(**inner).clone(); length];
+            Ok(EvmAbiValue::Array(decode_tuple(&types, data, position + 32)?))
+        },
+        EvmAbiParamType::FixedArray(inner, size) => {
+            if *size > data.len().saturating_sub(position) / 32 {
+                return Err(EvmError::ErrorAbiCoding(format!("array length {} is out of range", size)));
+            }
+            let types = vec![(**inner).clone(); *size];
+            Ok(EvmAbiValue::FixedArray(decode_tuple(&types, data, position)?))
+        },
+        EvmAbiParamType::Tuple(components) => {
+            Ok(EvmAbiValue::Tuple(decode_tuple(components, data, position)?))
+        },
+    }
+}
+
+/// Read 32-byte word at `position`.
+fn read_word(data: &[u8], position: usize) -> Result<&[u8], EvmError> {
+    match position.checked_add(32) {
+        Some(end) if end <= data.len() => Ok(&data[position..end]),
+        _ => Err(EvmError::ErrorAbiCoding(format!("data is too short to read a word at {}", position))),
+    }
+}
+
+/// Read 32-byte word at `position` as offset, or length.
+fn read_usize(data: &[u8], position: usize) -> Result<usize, EvmError> {
+    let value = U256::from_big_endian(read_word(data, position)?);
+    if value > U256::from(data.len()) {
+        return Err(EvmError::ErrorAbiCoding(format!("offset or length {} is out of range", value)));
+    }
+    Ok(value.as_usize())
+}
+
+/// Read length-prefixed bytes at `position`.
+fn read_bytes(data: &[u8], position: usize) -> Result<&[u8], EvmError> {
+    let length = read_usize(data, position)?;
+    let start = position + 32;
+    match start.checked_add(length) {
+        Some(end) if end <= data.len() => Ok(&data[start..end]),
+        _ => Err(EvmError::ErrorAbiCoding(format!("data is too short to read {} bytes at {}", length, start))),
+    }
+}
+
+/// Check that padding bytes are all zero.
+fn check_zero(padding: &[u8], t: &EvmAbiParamType) -> Result<(), EvmError> {
+    if padding.iter().any(|b| *b != 0) {
+        return Err(EvmError::ErrorAbiCoding(format!("non-zero padding for {}", t)));
+    }
+    Ok(())
+}
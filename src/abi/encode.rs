@@ -0,0 +1,210 @@
+use crate::prelude::*;
+use crate::types::evm_types::{EvmContractABIItem, EvmContractABIItemKind};
+use crate::types::abi_types::*;
+use crate::abi::signature::{function_selector, parse_signature};
+
+/// Encode bytes into hexadecimal string prefixed with `0x`.
+///
+/// # Arguments
+/// * `data` - bytes to encode
+pub fn encode_hex(data: &[u8]) -> String {
+    format!("0x{}", hex::encode(data))
+}
+
+/// Encode values according to the specified types as if they are members of
+/// a tuple. Each value must match its type.
+///
+/// # Arguments
+/// * `types` - types of values
+/// * `values` - values to encode
+pub fn encode(types: &[EvmAbiParamType], values: &[EvmAbiValue]) -> Result<Vec<u8>, EvmError> {
+    encode_tuple(types, values)
+}
+
+/// Encode function call i.e. 4-byte selector followed by encoded arguments.
+///
+/// # Arguments
+/// * `signature` - function signature e.g. `transfer(address,uint256)`. Output
+///   types can be appended as in `balanceOf(address)(uint256)`, they are ignored.
+/// * `args` - arguments of the call
+pub fn encode_function_call(signature: &str, args: &[EvmAbiValue]) -> Result<Vec<u8>, EvmError> {
+    let (input_signature, _) = parse_call_signature(signature)?;
+    let (name, types) = parse_signature(input_signature)?;
+    let canonical: Vec<String> = types.iter().map(|t| t.canonical()).collect();
+
+    let mut res = function_selector(&format!("{}({})", name, canonical.join(","))).to_vec();
+    res.extend(encode(&types, args)?);
+    Ok(res)
+}
+
+/// Encode function call of `name` according to contract ABI. For overloaded
+/// function, the first one whose inputs accept `args` is used. Overload with
+/// unsupported type is skipped.
+///
+/// Return encoded call along with the matched ABI item which can be used to
+/// decode its output with `decode::decode_function_output()`.
+///
+/// # Arguments
+/// * `abi` - contract ABI
+/// * `name` - function name
+/// * `args` - arguments of the call
+pub fn encode_function_call_from_abi<'a>(abi: &'a [EvmContractABIItem], name: &str, args: &[EvmAbiValue]) -> Result<(Vec<u8>, &'a EvmContractABIItem), EvmError> {
+    let mut last_err = None;
+    let candidates = abi.iter()
+        .filter(|item| item.r#type == EvmContractABIItemKind::Function && item.name.as_deref() == Some(name));
+
+    for item in candidates {
+        // overload with unsupported type is skipped in favor of the next one
+        let encoded = EvmAbiParamType::from_abi_params(item.inputs.as_deref().unwrap_or_default())
+            .and_then(|types| encode(&types, args))
+            .and_then(|encoded| Ok((item.selector()?, encoded)));
+        match encoded {
+            Ok((selector, encoded)) => {
+                let mut res = selector.to_vec();
+                res.extend(encoded);
+                return Ok((res, item));
+            },
+            Err(e) => last_err = Some(e),
+        }
+    }
+
+    match last_err {
+        Some(e) => Err(e),
+        None => Err(EvmError::ErrorAbiCoding(format!("no function '{}' in ABI", name))),
+    }
+}
+
+/// Split call signature into its function signature, and output types if
+/// they are appended e.g. `balanceOf(address)(uint256)`.
+///
+/// # Arguments
+/// * `signature` - call signature
+pub fn parse_call_signature(signature: &str) -> Result<(&str, Option<Vec<EvmAbiParamType>>), EvmError> {
+    let signature = signature.trim();
+    let mut depth = 0i32;
+    for (i, c) in signature.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    let (input, output) = signature.split_at(i + 1);
+                    let output = output.trim();
+                    if output.is_empty() {
+                        return Ok((input, None));
+                    }
+                    return match EvmAbiParamType::parse(output)? {
+                        EvmAbiParamType::Tuple(types) => Ok((input, Some(types))),
+                        _ => Err(EvmError::ErrorAbiCoding(format!("invalid output types in signature '{}'", signature))),
+                    };
+                }
+            },
+            _ => (),
+        }
+    }
+    Err(EvmError::ErrorAbiCoding(format!("invalid signature '{}'", signature)))
+}
+
+/// Encode members of tuple.
+fn encode_tuple(types: &[EvmAbiParamType], values: &[EvmAbiValue]) -> Result<Vec<u8>, EvmError> {
+    if types.len() != values.len() {
+        return Err(EvmError::ErrorAbiCoding(format!("expect {} values, but got {}", types.len(), values.len())));
+    }
+
+    let head_size: usize = types.iter().map(|t| t.head_size()).sum();
+    let mut head = Vec::with_capacity(head_size);
+    let mut tail = Vec::new();
+
+    for (t, v) in types.iter().zip(values.iter()) {
+        let encoded = encode_param(t, v)?;
+        if t.is_dynamic() {
+            head.extend(word_from_usize(head_size + tail.len()));
+            tail.extend(encoded);
+        }
+        else {
+            head.extend(encoded);
+        }
+    }
+
+    head.extend(tail);
+    Ok(head)
+}
+
+/// Encode a single value.
+fn encode_param(t: &EvmAbiParamType, v: &EvmAbiValue) -> Result<Vec<u8>, EvmError> {
+    let mismatch = || EvmError::ErrorAbiCoding(format!("value {:?} doesn't match type {}", v, t));
+
+    match (t, v) {
+        (EvmAbiParamType::Address, EvmAbiValue::Address(address)) => {
+            let mut word = vec![0u8; 12];
+            word.extend_from_slice(address.as_bytes());
+            Ok(word)
+        },
+        (EvmAbiParamType::Bool, EvmAbiValue::Bool(value)) => Ok(word_from_usize(*value as usize)),
+        (EvmAbiParamType::Function, EvmAbiValue::Function(address, selector)) => {
+            let mut word = address.as_bytes().to_vec();
+            word.extend_from_slice(selector);
+            word.resize(32, 0);
+            Ok(word)
+        },
+        (EvmAbiParamType::Uint(bits), EvmAbiValue::Uint(value)) => {
+            if value.bits() > *bits {
+                return Err(EvmError::ErrorAbiCoding(format!("value {} is out of range for {}", value, t)));
+            }
+            let mut word = vec![0u8; 32];
+            value.to_big_endian(&mut word);
+            Ok(word)
+        },
+        (EvmAbiParamType::Int(bits), EvmAbiValue::Int(value)) => {
+            let limit = U256::one() << (bits - 1);
+            let is_in_range = if value.is_negative() { value.abs() <= limit } else { value.0 < limit };
+            if !is_in_range {
+                return Err(EvmError::ErrorAbiCoding(format!("value {} is out of range for {}", value, t)));
+            }
+            let mut word = vec![0u8; 32];
+            value.0.to_big_endian(&mut word);
+            Ok(word)
+        },
+        (EvmAbiParamType::FixedBytes(size), EvmAbiValue::FixedBytes(bytes)) => {
+            if bytes.len() != *size {
+                return Err(mismatch());
+            }
+            let mut word = bytes.clone();
+            word.resize(32, 0);
+            Ok(word)
+        },
+        (EvmAbiParamType::Bytes, EvmAbiValue::Bytes(bytes)) => Ok(encode_bytes(bytes)),
+        (EvmAbiParamType::String, EvmAbiValue::String(text)) => Ok(encode_bytes(text.as_bytes())),
+        (EvmAbiParamType::Array(inner), EvmAbiValue::Array(values)) => {
+            let types = vec![(**inner).clone(); values.len()];
+            let mut res = word_from_usize(values.len());
+            res.extend(encode_tuple(&types, values)?);
+            Ok(res)
+        },
+        (EvmAbiParamType::FixedArray(inner, size), EvmAbiValue::FixedArray(values)) => {
+            if values.len() != *size {
+                return Err(mismatch());
+            }
+            let types = vec![(**inner).clone(); *size];
+            encode_tuple(&types, values)
+        },
+        (EvmAbiParamType::Tuple(components), EvmAbiValue::Tuple(values)) => encode_tuple(components, values),
+        _ => Err(mismatch()),
+    }
+}
+
+/// Encode length-prefixed bytes padded to multiple of 32 bytes.
+fn encode_bytes(bytes: &[u8]) -> Vec<u8> {
+    let mut res = word_from_usize(bytes.len());
+    res.extend_from_slice(bytes);
+    let padded_len = 32 + bytes.len().div_ceil(32) * 32;
+    res.resize(padded_len, 0);
+    res
+}
+
+/// Encode `usize` as 32-byte word.
+fn word_from_usize(value: usize) -> Vec<u8> {
+    let mut word = vec![0u8; 32];
+    U256::from(value).to_big_endian(&mut word);
+    word
+}
@@ -0,0 +1,335 @@
+use crate::prelude::*;
+use crate::types::evm_types::{EvmContractABIItem, EvmContractABIItemKind};
+use crate::types::abi_types::EvmAbiParamType;
+
+use std::collections::HashMap;
+use tiny_keccak::{Hasher, Keccak};
+
+/// Compute keccak-256 hash of data.
+///
+/// # Arguments
+/// * `data` - data to hash
+pub fn keccak256(data: &[u8]) -> H256 {
+    let mut hasher = Keccak::v256();
+    let mut res = [0u8; 32];
+    hasher.update(data);
+    hasher.finalize(&mut res);
+    H256::from(res)
+}
+
+/// Compute 4-byte function selector from canonical function signature
+/// e.g. `transfer(address,uint256)`.
+///
+/// # Arguments
+/// * `signature` - canonical function signature
+pub fn function_selector(signature: &str) -> [u8; 4] {
+    let hash = keccak256(signature.as_bytes());
+    let mut res = [0u8; 4];
+    res.copy_from_slice(&hash.as_bytes()[..4]);
+    res
+}
+
+impl EvmContractABIItem {
+    /// Canonical signature of function, event, or error e.g. `transfer(address,uint256)`.
+    /// Tuple is expanded into its components.
+    ///
+    /// Return `Err` for item which has no name i.e. constructor, receive, and fallback.
+    pub fn signature(&self) -> Result<String, EvmError> {
+        let name = match self.name {
+            Some(ref name) if self.r#type != EvmContractABIItemKind::Constructor => name,
+            _ => return Err(EvmError::ErrorAbiCoding(format!("ABI item of type {:?} has no signature", self.r#type))),
+        };
+
+        let types = match self.inputs {
+            Some(ref inputs) => EvmAbiParamType::from_abi_params(inputs)?,
+            None => Vec::new(),
+        };
+        let types: Vec<String> = types.iter().map(|t| t.canonical()).collect();
+
+        Ok(format!("{}({})", name, types.join(",")))
+    }
+
+    /// 4-byte selector of function, or error.
+    pub fn selector(&self) -> Result<[u8; 4], EvmError> {
+        Ok(function_selector(&self.signature()?))
+    }
+}
+
+/// Compute 32-byte event topic from canonical event signature
+/// e.g. `Transfer(address,address,uint256)`.
+///
+/// # Arguments
+/// * `signature` - canonical event signature
+pub fn event_topic(signature: &str) -> H256 {
+    keccak256(signature.as_bytes())
+}
+
+/// Parse function, event, or error signature into its name, and parameter
+/// types. Whitespaces, and type aliases e.g. `uint` are accepted, but not
+/// parameter names.
+///
+/// # Arguments
+/// * `signature` - signature e.g. `transfer(address,uint256)`
+pub fn parse_signature(signature: &str) -> Result<(String, Vec<EvmAbiParamType>), EvmError> {
+    let signature = signature.trim();
+    let err = || EvmError::ErrorAbiCoding(format!("invalid signature '{}'", signature));
+
+    let open_index = signature.find('(').ok_or_else(err)?;
+    let name = signature[..open_index].trim();
+    let params = signature[open_index+1..].strip_suffix(')').ok_or_else(err)?;
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$') {
+        return Err(err());
+    }
+
+    let mut types = Vec::new();
+    for param in EvmAbiParamType::split_top_level(params) {
+        types.push(EvmAbiParamType::parse(param)?);
+    }
+    Ok((name.to_owned(), types))
+}
+
+/// Normalize signature into its canonical form.
+///
+/// # Arguments
+/// * `signature` - signature e.g. `balanceOf( address )`
+pub fn canonical_signature(signature: &str) -> Result<String, EvmError> {
+    let (name, types) = parse_signature(signature)?;
+    let types: Vec<String> = types.iter().map(|t| t.canonical()).collect();
+    Ok(format!("{}({})", name, types.join(",")))
+}
+
+/// Function signatures of ERC-20, ERC-721, ERC-1155, wrapped native token,
+/// common DEX routers, and multicall contracts which are used to seed
+/// `EvmSignatureDatabase::create_with_defaults()`.
+static DEFAULT_FUNCTION_SIGNATURES: &[&str] = &[
+    // ERC-20
+    "totalSupply()",
+    "name()",
+    "symbol()",
+    "decimals()",
+    "balanceOf(address)",
+    "allowance(address,address)",
+    "transfer(address,uint256)",
+    "transferFrom(address,address,uint256)",
+    "approve(address,uint256)",
+    "increaseAllowance(address,uint256)",
+    "decreaseAllowance(address,uint256)",
+    "permit(address,address,uint256,uint256,uint8,bytes32,bytes32)",
+    // ERC-721
+    "ownerOf(uint256)",
+    "getApproved(uint256)",
+    "isApprovedForAll(address,address)",
+    "setApprovalForAll(address,bool)",
+    "safeTransferFrom(address,address,uint256)",
+    "safeTransferFrom(address,address,uint256,bytes)",
+    "tokenURI(uint256)",
+    "supportsInterface(bytes4)",
+    // ERC-1155
+    "balanceOf(address,uint256)",
+    "balanceOfBatch(address[],uint256[])",
+    "safeTransferFrom(address,address,uint256,uint256,bytes)",
+    "safeBatchTransferFrom(address,address,uint256[],uint256[],bytes)",
+    "uri(uint256)",
+    // wrapped native token
+    "deposit()",
+    "withdraw(uint256)",
+    // Uniswap V2 router, and its forks e.g. PancakeSwap
+    "swapExactTokensForTokens(uint256,uint256,address[],address,uint256)",
+    "swapTokensForExactTokens(uint256,uint256,address[],address,uint256)",
+    "swapExactETHForTokens(uint256,address[],address,uint256)",
+    "swapTokensForExactETH(uint256,uint256,address[],address,uint256)",
+    "swapExactTokensForETH(uint256,uint256,address[],address,uint256)",
+    "swapETHForExactTokens(uint256,address[],address,uint256)",
+    "swapExactTokensForTokensSupportingFeeOnTransferTokens(uint256,uint256,address[],address,uint256)",
+    "swapExactETHForTokensSupportingFeeOnTransferTokens(uint256,address[],address,uint256)",
+    "swapExactTokensForETHSupportingFeeOnTransferTokens(uint256,uint256,address[],address,uint256)",
+    "addLiquidity(address,address,uint256,uint256,uint256,uint256,address,uint256)",
+    "addLiquidityETH(address,uint256,uint256,uint256,address,uint256)",
+    "removeLiquidity(address,address,uint256,uint256,uint256,address,uint256)",
+    "removeLiquidityETH(address,uint256,uint256,uint256,address,uint256)",
+    // Uniswap V2 pair
+    "getReserves()",
+    "swap(uint256,uint256,address,bytes)",
+    // Uniswap V3 router
+    "exactInputSingle((address,address,uint24,address,uint256,uint256,uint256,uint160))",
+    "exactInput((bytes,address,uint256,uint256,uint256))",
+    "exactOutputSingle((address,address,uint24,address,uint256,uint256,uint256,uint160))",
+    "exactOutput((bytes,address,uint256,uint256,uint256))",
+    "multicall(bytes[])",
+    "multicall(uint256,bytes[])",
+    // Multicall, Multicall2, and Multicall3
+    "aggregate((address,bytes)[])",
+    "tryAggregate(bool,(address,bytes)[])",
+    "blockAndAggregate((address,bytes)[])",
+    "tryBlockAndAggregate(bool,(address,bytes)[])",
+    "aggregate3((address,bool,bytes)[])",
+    "aggregate3Value((address,bool,uint256,bytes)[])",
+];
+
+/// Event signatures used to seed `EvmSignatureDatabase::create_with_defaults()`.
+static DEFAULT_EVENT_SIGNATURES: &[&str] = &[
+    // ERC-20, and ERC-721 share the same topic for both events
+    "Transfer(address,address,uint256)",
+    "Approval(address,address,uint256)",
+    // ERC-721
+    "ApprovalForAll(address,address,bool)",
+    // ERC-1155
+    "TransferSingle(address,address,address,uint256,uint256)",
+    "TransferBatch(address,address,address,uint256[],uint256[])",
+    "URI(string,uint256)",
+    // wrapped native token
+    "Deposit(address,uint256)",
+    "Withdrawal(address,uint256)",
+    // Uniswap V2 pair
+    "Swap(address,uint256,uint256,uint256,uint256,address)",
+    "Sync(uint112,uint112)",
+    "Mint(address,uint256,uint256)",
+    "Burn(address,uint256,uint256,address)",
+    "PairCreated(address,address,address,uint256)",
+    // Uniswap V3 pool
+    "Swap(address,address,int256,int256,uint160,uint128,int24)",
+];
+
+/// Error signatures used to seed `EvmSignatureDatabase::create_with_defaults()`.
+static DEFAULT_ERROR_SIGNATURES: &[&str] = &[
+    "Error(string)",
+    "Panic(uint256)",
+];
+
+/// Local, and extensible database of function, event, and error signatures
+/// keyed by their selector or topic. It is used to resolve selector of
+/// un-verified contract into candidate signatures.
+///
+/// Multiple signatures can share the same selector, so lookup always returns
+/// all candidates in the order they were added.
+#[derive(Debug, Clone, Default)]
+pub struct EvmSignatureDatabase {
+    functions: HashMap<[u8; 4], Vec<String>>,
+    events: HashMap<H256, Vec<String>>,
+    errors: HashMap<[u8; 4], Vec<String>>,
+}
+
+impl EvmSignatureDatabase {
+    /// Create an empty database.
+    pub fn create() -> EvmSignatureDatabase {
+        EvmSignatureDatabase::default()
+    }
+
+    /// Create a database seeded with signatures of ERC-20, ERC-721, ERC-1155,
+    /// common DEX routers, and multicall contracts.
+    pub fn create_with_defaults() -> EvmSignatureDatabase {
+        let mut db = EvmSignatureDatabase::create();
+        // seeded signatures are known to be valid
+        for signature in DEFAULT_FUNCTION_SIGNATURES {
+            db.add_function_signature(signature).unwrap();
+        }
+        for signature in DEFAULT_EVENT_SIGNATURES {
+            db.add_event_signature(signature).unwrap();
+        }
+        for signature in DEFAULT_ERROR_SIGNATURES {
+            db.add_error_signature(signature).unwrap();
+        }
+        db
+    }
+
+    /// Add function signature. Return its selector.
+    ///
+    /// # Arguments
+    /// * `signature` - function signature e.g. `transfer(address,uint256)`
+    pub fn add_function_signature(&mut self, signature: &str) -> Result<[u8; 4], EvmError> {
+        let signature = canonical_signature(signature)?;
+        let selector = function_selector(&signature);
+        EvmSignatureDatabase::insert(&mut self.functions, selector, signature);
+        Ok(selector)
+    }
+
+    /// Add event signature. Return its topic.
+    ///
+    /// # Arguments
+    /// * `signature` - event signature e.g. `Transfer(address,address,uint256)`
+    pub fn add_event_signature(&mut self, signature: &str) -> Result<H256, EvmError> {
+        let signature = canonical_signature(signature)?;
+        let topic = event_topic(&signature);
+        EvmSignatureDatabase::insert(&mut self.events, topic, signature);
+        Ok(topic)
+    }
+
+    /// Add error signature. Return its selector.
+    ///
+    /// # Arguments
+    /// * `signature` - error signature e.g. `InsufficientBalance(uint256,uint256)`
+    pub fn add_error_signature(&mut self, signature: &str) -> Result<[u8; 4], EvmError> {
+        let signature = canonical_signature(signature)?;
+        let selector = function_selector(&signature);
+        EvmSignatureDatabase::insert(&mut self.errors, selector, signature);
+        Ok(selector)
+    }
+
+    /// Import signatures of all functions, events, and errors from contract ABI
+    /// e.g. as returned from `Contracts::get_abi_typed()`. Items whose
+    /// signature can't be derived e.g. library function taking `storage`
+    /// reference are skipped. Return number of items imported.
+    ///
+    /// # Arguments
+    /// * `abi` - contract ABI
+    pub fn import_abi(&mut self, abi: &[EvmContractABIItem]) -> Result<usize, EvmError> {
+        let mut count = 0usize;
+        for item in abi {
+            if !matches!(item.r#type, EvmContractABIItemKind::Function | EvmContractABIItemKind::Event | EvmContractABIItemKind::Error) {
+                continue;
+            }
+            let signature = match item.signature() {
+                Ok(signature) => signature,
+                Err(_) => continue,
+            };
+            match item.r#type {
+                EvmContractABIItemKind::Function => { self.add_function_signature(&signature)?; },
+                EvmContractABIItemKind::Event => { self.add_event_signature(&signature)?; },
+                _ => { self.add_error_signature(&signature)?; },
+            }
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// Import signatures from contract ABI in JSON text e.g. as returned from
+    /// `Contracts::get_abi()`. Return number of items imported.
+    ///
+    /// # Arguments
+    /// * `abi_text` - contract ABI in JSON text
+    pub fn import_abi_text(&mut self, abi_text: &str) -> Result<usize, EvmError> {
+        self.import_abi(&crate::deserialize::parse_abi(abi_text)?)
+    }
+
+    /// Get candidate function signatures of the selector.
+    ///
+    /// # Arguments
+    /// * `selector` - 4-byte function selector
+    pub fn lookup_function(&self, selector: &[u8; 4]) -> &[String] {
+        self.functions.get(selector).map_or(&[], |v| v.as_slice())
+    }
+
+    /// Get candidate event signatures of the topic.
+    ///
+    /// # Arguments
+    /// * `topic` - event topic i.e. first topic of non-anonymous event log
+    pub fn lookup_event(&self, topic: &H256) -> &[String] {
+        self.events.get(topic).map_or(&[], |v| v.as_slice())
+    }
+
+    /// Get candidate error signatures of the selector.
+    ///
+    /// # Arguments
+    /// * `selector` - 4-byte error selector
+    pub fn lookup_error(&self, selector: &[u8; 4]) -> &[String] {
+        self.errors.get(selector).map_or(&[], |v| v.as_slice())
+    }
+
+    /// Insert signature without duplication.
+    fn insert<K: std::hash::Hash + Eq>(map: &mut HashMap<K, Vec<String>>, key: K, signature: String) {
+        let candidates = map.entry(key).or_default();
+        if !candidates.contains(&signature) {
+            candidates.push(signature);
+        }
+    }
+}
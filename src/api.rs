@@ -8,3 +8,21 @@ pub mod stats;
 
 /// Contracts API group / namespace
 pub mod contracts;
+
+/// Proxy API group / namespace
+pub mod proxy;
+
+/// Transactions API group / namespace
+pub mod transactions;
+
+/// Blocks API group / namespace
+pub mod blocks;
+
+/// Logs API group / namespace
+pub mod logs;
+
+/// Gas Tracker API group / namespace
+pub mod gastracker;
+
+/// Internal HTTP helpers shared across namespaces
+pub(crate) mod http;
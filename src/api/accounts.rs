@@ -166,7 +166,29 @@ impl Accounts {
     pub fn get_balance_address(&self, ctx: &Context, address: &str) -> Result<U256, EvmError> {
         let raw_url_str = format!("{}/api?module=account&action=balance&address={target_address}&tag=latest&apikey={api_key}", Context::get_prefix_url(ctx.chain), target_address=address, api_key=ctx.api_key);
 
-        let url = match Url::parse(&raw_url_str) {
+        self.get_balance(&raw_url_str)
+    }
+
+    /// `PRO API` Get historical balance of specified address at the specified
+    /// block number.
+    ///
+    /// # Arguments
+    /// * `ctx` - context instance
+    /// * `address` - target wallet or contract address to get balance of
+    /// * `block_number` - block number to get balance at
+    pub fn get_balance_address_at_block(&self, ctx: &Context, address: &str, block_number: u64) -> Result<U256, EvmError> {
+        let raw_url_str = format!("{}/api?module=account&action=balancehistory&address={target_address}&blockno={block_number}&apikey={api_key}", Context::get_prefix_url(ctx.chain), target_address=address, block_number=block_number, api_key=ctx.api_key);
+
+        self.get_balance(&raw_url_str)
+    }
+
+    /// Internal function supporting to get balance of a single address for
+    /// both latest, and historical one.
+    ///
+    /// # Arguments
+    /// * `raw_url_str` - raw URL string to make a request to
+    fn get_balance(&self, raw_url_str: &str) -> Result<U256, EvmError> {
+        let url = match Url::parse(raw_url_str) {
             Ok(res) => res,
             Err(_) => return Err(EvmError::ErrorInternalUrlParsing),
         };
@@ -323,7 +345,7 @@ impl Accounts {
     ///
     /// **NOTE**: This function **doesn't** internally check whether the specified address is
     /// in fact EOA address, and not contract address. Thus it will return error
-    /// instead.
+    /// instead. Use `Proxy::is_contract()` to check beforehand if needed.
     ///
     /// # Arguments
     /// * `ctx` - context instance
@@ -412,4 +434,223 @@ impl Accounts {
 
         Ok(ret_txs)
     }
+
+    /// Get native token balance over time of specified address as reconstructed
+    /// from its normal and internal transactions. The final reconstructed balance
+    /// is cross-checked against the balance as reported by API.
+    ///
+    /// **NOTE**: Listing of normal and internal transactions are limited to
+    /// 10,000 records each, and native token received by other means e.g. block
+    /// rewards are not part of such listing. Check `is_discrepant()` of the
+    /// result to know whether the reconstruction can be trusted.
+    ///
+    /// # Arguments
+    /// * `ctx` - context instance
+    /// * `address` - target wallet or contract address
+    /// * `is_check_at_end_block` - whether to cross-check against historical
+    ///   balance at the last block found in the history (`PRO API`). Otherwise
+    ///   cross-check against the latest balance.
+    pub fn get_native_balance_history(&self, ctx: &Context, address: &str, is_check_at_end_block: bool) -> Result<EvmNativeBalanceHistory, EvmError> {
+        let normal_txs = self.get_list_normal_transactions(ctx, address)?;
+        let internal_txs = self.get_list_internal_transactions(ctx, address)?;
+
+        let mut history = Accounts::reconstruct_native_balance_history(address, &normal_txs, &internal_txs);
+
+        let reported_balance = match (is_check_at_end_block, history.points.last()) {
+            (true, Some(last_point)) => self.get_balance_address_at_block(ctx, address, last_point.block_number)?,
+            _ => self.get_balance_address(ctx, address)?,
+        };
+        history.reported_balance = Some(reported_balance);
+
+        Ok(history)
+    }
+
+    /// Reconstruct native token balance over time of specified address from
+    /// its already fetched normal and internal transactions.
+    ///
+    /// Value of successful transactions is counted towards the balance, and gas
+    /// fee (`gas_used * gas_price`) is deducted for every normal transaction sent
+    /// by `address` regardless of whether it has failed.
+    ///
+    /// Returned result is not cross-checked, thus its `reported_balance` is `None`.
+    ///
+    /// # Arguments
+    /// * `address` - target wallet or contract address
+    /// * `normal_txs` - normal transactions of `address`
+    /// * `internal_txs` - internal transactions of `address`
+    pub fn reconstruct_native_balance_history(address: &str, normal_txs: &[EvmNormalTransactionResponseSuccessVariantResult], internal_txs: &[EvmInternalTransactionResponseSuccessVariantResult]) -> EvmNativeBalanceHistory {
+        let address = address.to_lowercase();
+
+        // (block number, timestamp, value in, value out, fee) of each transaction
+        let mut changes: Vec<(u64, u64, U256, U256, U256)> = Vec::with_capacity(normal_txs.len() + internal_txs.len());
+
+        for tx in normal_txs {
+            let is_sender = tx.from.to_lowercase() == address;
+            let is_receiver = tx.to.to_lowercase() == address;
+
+            let mut change = (tx.block_number, tx.timestamp, U256::zero(), U256::zero(), U256::zero());
+            if is_sender {
+                change.4 = U256::from(tx.gas_used) * U256::from(tx.gas_price);
+            }
+            if !tx.is_error {
+                if is_receiver {
+                    change.2 = tx.value;
+                }
+                if is_sender {
+                    change.3 = tx.value;
+                }
+            }
+            changes.push(change);
+        }
+
+        for tx in internal_txs.iter().filter(|tx| !tx.is_error) {
+            let value_in = if tx.to.to_lowercase() == address { tx.value } else { U256::zero() };
+            let value_out = if tx.from.to_lowercase() == address { tx.value } else { U256::zero() };
+            changes.push((tx.block_number, tx.timestamp, value_in, value_out, U256::zero()));
+        }
+
+        // stable sort, so order within the same block is kept
+        changes.sort_by_key(|change| change.0);
+
+        let mut points: Vec<EvmNativeBalancePoint> = Vec::new();
+        let mut balance = U256::zero();
+        let mut is_underflow = false;
+
+        for (block_number, timestamp, value_in, value_out, fee) in changes {
+            balance = balance.saturating_add(value_in);
+            match balance.checked_sub(value_out.saturating_add(fee)) {
+                Some(res) => balance = res,
+                None => {
+                    balance = U256::zero();
+                    is_underflow = true;
+                }
+            }
+
+            match points.last_mut() {
+                Some(point) if point.block_number == block_number => {
+                    point.value_in = point.value_in.saturating_add(value_in);
+                    point.value_out = point.value_out.saturating_add(value_out);
+                    point.fee = point.fee.saturating_add(fee);
+                    point.balance = balance;
+                },
+                _ => {
+                    points.push(EvmNativeBalancePoint {
+                        block_number,
+                        timestamp,
+                        value_in,
+                        value_out,
+                        fee,
+                        balance,
+                    });
+                },
+            }
+        }
+
+        EvmNativeBalanceHistory {
+            points,
+            reconstructed_balance: balance,
+            is_underflow,
+            reported_balance: None,
+        }
+    }
+
+    /// Get ERC-20/BEP-20 token holdings of specified address as reconstructed
+    /// from its transfer events.
+    ///
+    /// **NOTE**: The same limitation as of `get_erc20_transfer_events_a()` applies.
+    ///
+    /// # Arguments
+    /// * `ctx` - context instance
+    /// * `address` - target wallet address
+    /// * `block_number` - block number to reconstruct holdings as of (inclusive).
+    ///   `None` for as of the latest transfer event.
+    pub fn get_erc20_holdings(&self, ctx: &Context, address: &str, block_number: Option<u64>) -> Result<EvmErc20HoldingsSnapshot, EvmError> {
+        let events = self.get_erc20_transfer_events_a(ctx, address)?;
+
+        Ok(Accounts::reconstruct_erc20_holdings(address, &events, block_number))
+    }
+
+    /// Reconstruct ERC-20/BEP-20 token holdings of specified address from its
+    /// already fetched transfer events. Each token is identified by its
+    /// `contract_address`.
+    ///
+    /// # Arguments
+    /// * `address` - target wallet address
+    /// * `events` - transfer events of `address`
+    /// * `block_number` - block number to reconstruct holdings as of (inclusive).
+    ///   `None` for as of the latest transfer event.
+    pub fn reconstruct_erc20_holdings(address: &str, events: &[EvmErc20TokenTransferEventResponseSuccessVariantResult], block_number: Option<u64>) -> EvmErc20HoldingsSnapshot {
+        let address = address.to_lowercase();
+
+        let mut sorted_events: Vec<&EvmErc20TokenTransferEventResponseSuccessVariantResult> = events.iter()
+            .filter(|event| block_number.is_none_or(|b| event.block_number <= b))
+            .collect();
+        // stable sort, so order within the same block is kept
+        sorted_events.sort_by_key(|event| event.block_number);
+
+        let mut holdings: Vec<EvmErc20Holding> = Vec::new();
+        // map from lower-cased contract address to index into `holdings`
+        let mut indexes: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
+
+        for event in sorted_events {
+            let contract_address = event.contract_address.to_lowercase();
+            let index = *indexes.entry(contract_address).or_insert_with(|| {
+                holdings.push(EvmErc20Holding {
+                    contract_address: event.contract_address.clone(),
+                    token_name: event.token_name.clone(),
+                    token_symbol: event.token_symbol.clone(),
+                    token_decimal: event.token_decimal,
+                    total_in: U256::zero(),
+                    total_out: U256::zero(),
+                    balance: U256::zero(),
+                    balance_f: 0.0,
+                    is_underflow: false,
+                    points: Vec::new(),
+                });
+                holdings.len() - 1
+            });
+            let holding = &mut holdings[index];
+
+            if event.to.to_lowercase() == address {
+                holding.total_in = holding.total_in.saturating_add(event.value);
+                holding.balance = holding.balance.saturating_add(event.value);
+            }
+            if event.from.to_lowercase() == address {
+                holding.total_out = holding.total_out.saturating_add(event.value);
+                match holding.balance.checked_sub(event.value) {
+                    Some(res) => holding.balance = res,
+                    None => {
+                        holding.balance = U256::zero();
+                        holding.is_underflow = true;
+                    }
+                }
+            }
+            holding.balance_f = Accounts::scale_token_amount(holding.balance, holding.token_decimal);
+
+            match holding.points.last_mut() {
+                Some(point) if point.block_number == event.block_number => {
+                    point.balance = holding.balance;
+                    point.balance_f = holding.balance_f;
+                },
+                _ => {
+                    holding.points.push(EvmErc20HoldingPoint {
+                        block_number: event.block_number,
+                        timestamp: event.timestamp,
+                        balance: holding.balance,
+                        balance_f: holding.balance_f,
+                    });
+                },
+            }
+        }
+
+        EvmErc20HoldingsSnapshot {
+            block_number,
+            holdings,
+        }
+    }
+
+    /// Scale raw token amount by its decimal.
+    fn scale_token_amount(amount: U256, decimal: u8) -> f64 {
+        amount.to_f64_lossy() / 10f64.powi(decimal as i32)
+    }
 }
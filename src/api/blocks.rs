@@ -0,0 +1,111 @@
+use crate::prelude::*;
+use crate::types::evm_types::*;
+use crate::api::http;
+use crate::environ::Context;
+
+/// Blocks namespace containing related APIs about block
+pub struct Blocks;
+
+impl Blocks {
+    /// Get reward of a block including its uncles.
+    ///
+    /// # Arguments
+    /// * `ctx` - context instance
+    /// * `block_number` - block number
+    pub fn get_block_reward(&self, ctx: &Context, block_number: u64) -> Result<EvmBlockReward, EvmError> {
+        let json = self.request::<EvmBlockRewardResponse>(ctx, "getblockreward", &format!("&blockno={}", block_number))?;
+        Blocks::block_reward_from_response(json)
+    }
+
+    /// Get estimated time remaining until a future block is mined. Return
+    /// `Err` if the block is already mined.
+    ///
+    /// # Arguments
+    /// * `ctx` - context instance
+    /// * `block_number` - future block number
+    pub fn get_block_countdown(&self, ctx: &Context, block_number: u64) -> Result<EvmBlockCountdown, EvmError> {
+        let json = self.request::<EvmBlockCountdownResponse>(ctx, "getblockcountdown", &format!("&blockno={}", block_number))?;
+        Blocks::block_countdown_from_response(json)
+    }
+
+    /// Get number of the block mined closest to a timestamp.
+    ///
+    /// # Arguments
+    /// * `ctx` - context instance
+    /// * `timestamp` - unix timestamp in seconds
+    /// * `closest` - whether to take the closest block before, or after timestamp
+    pub fn get_block_number_by_timestamp(&self, ctx: &Context, timestamp: u64, closest: EvmBlockClosest) -> Result<u64, EvmError> {
+        let json = self.request::<EvmBlockNumberResponse>(ctx, "getblocknobytime", &format!("&timestamp={}&closest={}", timestamp, closest.as_str()))?;
+        Blocks::block_number_from_response(json)
+    }
+
+    /// Get inclusive range of blocks mined within time range. Use it to query
+    /// other APIs which accept block range e.g. listing of transactions.
+    ///
+    /// # Arguments
+    /// * `ctx` - context instance
+    /// * `start_timestamp` - unix timestamp in seconds of range's start, inclusive
+    /// * `end_timestamp` - unix timestamp in seconds of range's end, inclusive
+    pub fn get_block_range_by_timestamps(&self, ctx: &Context, start_timestamp: u64, end_timestamp: u64) -> Result<(u64, u64), EvmError> {
+        if start_timestamp > end_timestamp {
+            return Err(EvmError::ErrorParameter(Some("'start_timestamp' cannot be after 'end_timestamp'".to_owned())));
+        }
+
+        let start = self.get_block_number_by_timestamp(ctx, start_timestamp, EvmBlockClosest::After)?;
+        let end = self.get_block_number_by_timestamp(ctx, end_timestamp, EvmBlockClosest::Before)?;
+        if start > end {
+            return Err(EvmError::ErrorParameter(Some(format!("no block is mined between timestamp {} and {}", start_timestamp, end_timestamp))));
+        }
+        Ok((start, end))
+    }
+
+    /// Internal function supporting to make request to blocks module, then
+    /// parse response as `T`.
+    ///
+    /// # Arguments
+    /// * `ctx` - context instance
+    /// * `action` - API action e.g. `getblockreward`
+    /// * `params` - query parameters, each one prefixed with `&`
+    fn request<T>(&self, ctx: &Context, action: &str, params: &str) -> Result<T, EvmError>
+    where
+        T: serde::de::DeserializeOwned
+    {
+        http::get_json(ctx, &format!("module=block&action={}{}", action, params))
+    }
+
+    /// Internal function supporting to get block reward from API response.
+    ///
+    /// # Arguments
+    /// * `json` - API response
+    pub(crate) fn block_reward_from_response(json: EvmBlockRewardResponse) -> Result<EvmBlockReward, EvmError> {
+        match json.result {
+            EvmBlockRewardResult::Success(reward) if json.status == "1" => Ok(reward),
+            EvmBlockRewardResult::Failed(txt) => Err(EvmError::ErrorApiResponse(format!("message:{}, result:{}", json.message, txt))),
+            _ => Err(EvmError::ErrorApiResponse(format!("message:{}", json.message))),
+        }
+    }
+
+    /// Internal function supporting to get block countdown from API response.
+    ///
+    /// # Arguments
+    /// * `json` - API response
+    pub(crate) fn block_countdown_from_response(json: EvmBlockCountdownResponse) -> Result<EvmBlockCountdown, EvmError> {
+        match json.result {
+            EvmBlockCountdownResult::Success(countdown) if json.status == "1" => Ok(countdown),
+            EvmBlockCountdownResult::Failed(txt) => Err(EvmError::ErrorApiResponse(format!("message:{}, result:{}", json.message, txt))),
+            _ => Err(EvmError::ErrorApiResponse(format!("message:{}", json.message))),
+        }
+    }
+
+    /// Internal function supporting to get block number from API response.
+    ///
+    /// # Arguments
+    /// * `json` - API response
+    pub(crate) fn block_number_from_response(json: EvmBlockNumberResponse) -> Result<u64, EvmError> {
+        if json.status != "1" {
+            return Err(EvmError::ErrorApiResponse(format!("message:{}, result:{}", json.message, json.result)));
+        }
+        json.result.parse::<u64>()
+            .map_err(|e| EvmError::ErrorJsonParsing(Some(format!("block number '{}'; err={}", json.result, e))))
+    }
+}
@@ -1,42 +1,123 @@
 use crate::prelude::*;
 use crate::types::evm_types::*;
+use crate::types::abi_types::EvmAbiDecodedCall;
+use crate::abi::decode::decode_function_input;
+use crate::deserialize::de_sources_map_to_vec_source_file;
 use crate::environ::Context;
+use crate::export;
+use crate::api::http;
 
 use isahc::prelude::*;
 use url::Url;
-use regex::Regex;
+
+/// Maximum number of addresses API accepts for getting contract creation
+const CONTRACT_CREATION_BATCH_SIZE: usize = 5;
+
+/// Exact text as returned from API for contract without verified source code
+static SOURCE_CODE_NOT_VERIFIED: &str = "Contract source code not verified";
 
 /// Contracts namespace containing related APIs about contracts
 pub struct Contracts;
 
 impl Contracts {
-    /// Check whether the content of text is in json format.
-    /// See
-    /// https://docs.soliditylang.org/en/v0.5.8/using-the-compiler.html#compiler-input-and-output-json-description
+    /// Get contract ABI.
     ///
-    /// With that we need to
+    /// # Arguments
+    /// * `ctx` - Context
+    /// * `address` - contract address to get ABI
+    /// * `is_pretty_print` - whether or not to pretty print
+    pub fn get_abi(&self, ctx: &Context, address: &str, is_pretty_print: bool) -> Result<String, EvmError> {
+        let abi_text = self.get_abi_text(ctx, address)?;
+
+        if is_pretty_print {
+            // deserialize json string into object
+            // in order to pretty print it later
+            let json_obj = Contracts::parse_abi(&abi_text)?;
+            match serde_json::to_string_pretty(&json_obj) {
+                Ok(pretty_json_string) => Ok(pretty_json_string),
+                Err(e) => Err(EvmError::ErrorInternalGeneric(Some(format!("create pretty JSON string from JSON object; err={}", e)))),
+            }
+        }
+        else {
+            Ok(abi_text)
+        }
+    }
+
+    /// Get contract ABI as typed structure.
     ///
     /// # Arguments
-    /// * `text` - text to check
-    fn is_content_in_json_format(text: &str) -> bool {
-        let lower_cased_text = text.to_lowercase();
-        let regex: Regex = Regex::new(r#".*"language":\s*"solidity".*"#).unwrap();
-        regex.is_match(&lower_cased_text)
+    /// * `ctx` - Context
+    /// * `address` - contract address to get ABI
+    pub fn get_abi_typed(&self, ctx: &Context, address: &str) -> Result<Vec<EvmContractABIItem>, EvmError> {
+        let abi_text = self.get_abi_text(ctx, address)?;
+        Contracts::parse_abi(&abi_text)
     }
 
-    /// Replace literal CRLF characters into ASCII code represent CR and LF
-    /// individually
-    fn replace_literal_crlf_with_char_codes(text: &str) -> String {
-        str::replace(&str::replace(text, "\\n", "\n"), "\\r", "\r")
+    /// Parse contract ABI in JSON text into typed structure.
+    ///
+    /// # Arguments
+    /// * `abi_text` - contract ABI in JSON text
+    pub fn parse_abi(abi_text: &str) -> Result<Vec<EvmContractABIItem>, EvmError> {
+        crate::deserialize::parse_abi(abi_text)
     }
 
-    /// Get contract ABI.
+    /// Decode input of every normal transaction in the listing against ABI of
+    /// the contract it calls.
+    ///
+    /// ABI is fetched once per `to` address, and cached for the rest of the
+    /// listing. Contract which doesn't have verified source code has no ABI.
+    /// Requests are spaced out so that no more than `requests_per_second`
+    /// requests are made in a second. Any other error e.g. rate limit, or
+    /// invalid API key is returned.
+    ///
+    /// Returned vector has the same length as `txs`. Its item is `None` if
+    /// such transaction cannot be decoded e.g. plain transfer of native token,
+    /// contract creation, calling to contract without ABI, or malformed input.
+    ///
+    /// # Arguments
+    /// * `ctx` - Context
+    /// * `txs` - normal transactions as returned from `Accounts::get_list_normal_transactions()`
+    /// * `requests_per_second` - rate limit of API key
+    pub fn decode_transactions_input(&self, ctx: &Context, txs: &[EvmNormalTransactionResponseSuccessVariantResult], requests_per_second: u32) -> Result<Vec<Option<EvmAbiDecodedCall>>, EvmError> {
+        let mut limiter = http::RateLimiter::create(requests_per_second)?;
+        let mut abi_cache: std::collections::HashMap<String, Option<Vec<EvmContractABIItem>>> = std::collections::HashMap::new();
+        let mut res = Vec::with_capacity(txs.len());
+
+        for tx in txs {
+            // 4-byte selector is 8 characters excluding `0x`
+            if tx.to.is_empty() || tx.input.trim_start_matches("0x").len() < 8 {
+                res.push(None);
+                continue;
+            }
+
+            let to = tx.to.to_lowercase();
+            if !abi_cache.contains_key(&to) {
+                limiter.wait();
+                let abi = match self.get_abi_typed(ctx, &to) {
+                    Ok(abi) => Some(abi),
+                    // un-verified contract, or not a contract at all
+                    Err(ref e) if Contracts::is_source_code_not_verified(e) => None,
+                    Err(e) => return Err(e),
+                };
+                abi_cache.insert(to.clone(), abi);
+            }
+
+            let decoded = match abi_cache[&to] {
+                Some(ref abi) => decode_function_input(abi, &tx.input).unwrap_or(None),
+                None => None,
+            };
+            res.push(decoded);
+        }
+
+        Ok(res)
+    }
+
+    /// Internal function supporting to get contract ABI in JSON text.
     ///
     /// # Arguments
     /// * `ctx` - Context
     /// * `address` - contract address to get ABI
-    /// * `is_pretty_print` - whether or not to pretty print
-    pub fn get_abi(self, ctx: &Context, address: &str, is_pretty_print: bool) -> Result<String, EvmError> {
+    fn get_abi_text(&self, ctx: &Context, address: &str) -> Result<String, EvmError> {
         let raw_url_str = format!("{}/api?module=contract&action=getabi&address={address}&apikey={api_key}", Context::get_prefix_url(ctx.chain), address=address, api_key=ctx.api_key);
 
         let url = match Url::parse(&raw_url_str) {
@@ -59,35 +140,7 @@ impl Contracts {
                 }
 
                 match res.json::<EvmContractABIResponse>() {
-                    Ok(json) => {
-                        if json.status == "1" {
-                            // clean the text e.g. \
-                            let mut cleaned_res = Contracts::replace_literal_crlf_with_char_codes(&json.result);
-                            cleaned_res = str::replace(&cleaned_res, "\\", "");
-
-                            if is_pretty_print {
-                                // deserialize json string into object
-                                // in order to pretty print it later
-                                match serde_json::from_str::<Vec<EvmContractABIItem>>(&cleaned_res) {
-                                    Ok(json_obj) => {
-                                        match serde_json::to_string_pretty(&json_obj) {
-                                            Ok(pretty_json_string) => Ok(pretty_json_string),
-                                            Err(e) => return Err(EvmError::ErrorInternalGeneric(Some(format!("create pretty JSON string from JSON object; err={}", e)))),
-                                        }
-                                    },
-                                    Err(e) => {
-                                        return Err(EvmError::ErrorInternalGeneric(Some(format!("create JSON object from string; err={}", e))));
-                                    },
-                                }
-                            }
-                            else {
-                                Ok(cleaned_res)
-                            }
-                        }
-                        else {
-                            return Err(EvmError::ErrorApiResponse(format!("message:{}", json.message)));
-                        }
-                    },
+                    Ok(json) => Contracts::abi_text_from_response(json),
                     Err(e) => {
                         eprintln!("{:?}", e);
                         return Err(EvmError::ErrorJsonParsing(None));
@@ -120,7 +173,402 @@ impl Contracts {
     /// This is due to the way API returned back. Even with mutiple source
     /// files uploaded and verified but it will altogether combined into one
     /// long string of code.
-    pub fn get_verified_source_code(self, ctx: &Context, address: &str) -> Result<(Vec<EvmContractSourceCode>, bool), EvmError> {
+    pub fn get_verified_source_code(&self, ctx: &Context, address: &str) -> Result<(Vec<EvmContractSourceCode>, bool), EvmError> {
+        let contracts = self.get_source_code_records(ctx, address)?;
+        Contracts::split_source_code_records(contracts)
+    }
+
+    /// Internal function supporting to split source code in JSON format of
+    /// the first record into multiple files as additional records. Interface
+    /// files of Vyper's Standard JSON input are included after source files.
+    /// Detected language is set to all records.
+    ///
+    /// # Arguments
+    /// * `contracts` - contract's metadata records as returned from API
+    pub(crate) fn split_source_code_records(mut contracts: Vec<EvmContractSourceCode>) -> Result<(Vec<EvmContractSourceCode>, bool), EvmError> {
+        // there can be a chance that source code is in json format. It allows
+        // clear multiple source files that we can better utilize and return
+        // as such instead of combined it altogether.
+        let input = Contracts::parse_source_code(&contracts[0].source_code)?;
+        let language = Contracts::detect_language(&contracts[0], input.as_ref());
+        for contract in contracts.iter_mut() {
+            contract.language = language.clone();
+        }
+
+        match input {
+            Some(input) => {
+                let mut additional_vec: Vec<EvmContractSourceCode> = input.sources.into_iter()
+                    .chain(input.interfaces)
+                    .map(|file| EvmContractSourceCode {
+                        source_code: file.content,
+                        contract_name: file.path,
+                        ..contracts[0].clone()
+                    })
+                    .collect();
+                contracts.append(&mut additional_vec);
+                Ok((contracts, true))
+            },
+            // source code is already unescaped by JSON parsing, so it's kept as-is
+            None => Ok((contracts, false)),
+        }
+    }
+
+    /// Get verified contract's source code from the specified address split
+    /// into files along with its compiler settings.
+    ///
+    /// # Arguments
+    /// * `ctx` - Context
+    /// * `address` - contract address to get verified source code
+    pub fn get_verified_source_code_typed(&self, ctx: &Context, address: &str) -> Result<EvmContractVerifiedSource, EvmError> {
+        let mut contracts = self.get_source_code_records(ctx, address)?;
+        Contracts::split_verified_source(contracts.swap_remove(0))
+    }
+
+    /// Get verified contract's source code from the specified address, then
+    /// write it into `dir` as Foundry project. See `export::export_foundry_project`.
+    /// Return paths of written files.
+    ///
+    /// # Arguments
+    /// * `ctx` - Context
+    /// * `address` - contract address to get verified source code
+    /// * `dir` - project directory, it will be created if not exist
+    pub fn export_verified_source_code(&self, ctx: &Context, address: &str, dir: &std::path::Path) -> Result<Vec<std::path::PathBuf>, EvmError> {
+        let verified = self.get_verified_source_code_typed(ctx, address)?;
+        export::export_foundry_project(&verified, dir)
+    }
+
+    /// Get verified source code, and ABI of the specified address then follow
+    /// its implementation as long as it is a proxy. Each implementation must
+    /// also have verified source code.
+    ///
+    /// Return `Err` with `ErrorProxyResolution` if there is a cycle in the
+    /// chain, or more than `max_depth` implementations need to be followed.
+    ///
+    /// # Arguments
+    /// * `ctx` - Context
+    /// * `address` - contract address to resolve
+    /// * `max_depth` - maximum number of implementations to follow
+    pub fn resolve_proxy(&self, ctx: &Context, address: &str, max_depth: usize) -> Result<EvmContractProxyResolution, EvmError> {
+        Contracts::resolve_proxy_with(address, max_depth, |addr| {
+            let mut contracts = self.get_source_code_records(ctx, addr)?;
+            Ok(contracts.swap_remove(0))
+        })
+    }
+
+    /// Merge ABI of multiple contracts together without duplicates. Items are
+    /// considered the same if they have the same type, and signature. Constructor,
+    /// receive, and fallback are considered the same by their type alone.
+    /// The first occurrence is kept.
+    ///
+    /// # Arguments
+    /// * `abis` - ABI of contracts in order of precedence
+    pub fn merge_abi(abis: &[&[EvmContractABIItem]]) -> Vec<EvmContractABIItem> {
+        let mut seen = std::collections::HashSet::new();
+        let mut res = Vec::new();
+        for item in abis.iter().flat_map(|abi| abi.iter()) {
+            let key = match item.r#type {
+                EvmContractABIItemKind::Constructor | EvmContractABIItemKind::Receive | EvmContractABIItemKind::Fallback => String::new(),
+                _ => item.signature().unwrap_or_else(|_| item.name.clone().unwrap_or_default()),
+            };
+            if seen.insert((item.r#type, key)) {
+                res.push(item.clone());
+            }
+        }
+        res
+    }
+
+    /// Internal function supporting to resolve proxy's implementation chain
+    /// with contract's metadata fetched by `fetch`.
+    ///
+    /// # Arguments
+    /// * `address` - contract address to resolve
+    /// * `max_depth` - maximum number of implementations to follow
+    /// * `fetch` - function to get contract's metadata of an address
+    pub(crate) fn resolve_proxy_with<F>(address: &str, max_depth: usize, mut fetch: F) -> Result<EvmContractProxyResolution, EvmError>
+    where
+        F: FnMut(&str) -> Result<EvmContractSourceCode, EvmError>
+    {
+        let mut levels: Vec<EvmContractProxyLevel> = Vec::new();
+        let mut visited = std::collections::HashSet::new();
+        let mut current = address.trim().to_owned();
+
+        loop {
+            if !visited.insert(current.to_lowercase()) {
+                let chain: Vec<&str> = levels.iter().map(|l| l.address.as_str()).collect();
+                return Err(EvmError::ErrorProxyResolution(format!("cycle detected at {} after {}", current, chain.join(" -> "))));
+            }
+            if levels.len() > max_depth {
+                return Err(EvmError::ErrorProxyResolution(format!("more than {} implementations to follow from {}", max_depth, address)));
+            }
+
+            let source = Contracts::split_verified_source(fetch(&current)?)?;
+            let abi = Contracts::parse_abi(&source.contract.abi)?;
+            let next = source.contract.implementation.trim().to_owned();
+            let is_proxy = source.contract.proxy;
+            levels.push(EvmContractProxyLevel { address: current, source, abi });
+
+            if !is_proxy || next.is_empty() {
+                break;
+            }
+            current = next;
+        }
+
+        let abis: Vec<&[EvmContractABIItem]> = levels.iter().map(|l| l.abi.as_slice()).collect();
+        let merged_abi = Contracts::merge_abi(&abis);
+        Ok(EvmContractProxyResolution { levels, merged_abi })
+    }
+
+    /// Get creator address, and creation transaction hash of contracts.
+    /// Addresses are queried in batches of 5 which is the maximum allowed by
+    /// API in a single request, so `addresses` can be of any non-zero length.
+    /// Batches are spaced out so that no more than `requests_per_second`
+    /// requests are made in a second e.g. 5 for free API key.
+    ///
+    /// Returned vector is in the same order as API returns for each batch.
+    /// Address which is not a contract, or not found has no item.
+    ///
+    /// # Arguments
+    /// * `ctx` - Context
+    /// * `addresses` - contract addresses to get creation info
+    /// * `requests_per_second` - rate limit of API key
+    pub fn get_contract_creation(&self, ctx: &Context, addresses: &[&str], requests_per_second: u32) -> Result<Vec<EvmContractCreation>, EvmError> {
+        if addresses.is_empty() {
+            return Err(EvmError::ErrorParameter(Some("'addresses' needs to be more than 0".to_owned())));
+        }
+        let mut limiter = http::RateLimiter::create(requests_per_second)?;
+
+        let mut res = Vec::with_capacity(addresses.len());
+        for chunk in addresses.chunks(CONTRACT_CREATION_BATCH_SIZE) {
+            limiter.wait();
+            res.extend(self.get_contract_creation_batch(ctx, chunk)?);
+        }
+        Ok(res)
+    }
+
+    /// Submit contract's source code for verification. Return GUID to check
+    /// its status with `check_verify_status()`, or `wait_for_verify_status()`.
+    ///
+    /// # Arguments
+    /// * `ctx` - Context
+    /// * `request` - verification request
+    pub fn verify_source_code(&self, ctx: &Context, request: &EvmContractVerificationRequest) -> Result<String, EvmError> {
+        if request.libraries.len() > 10 {
+            return Err(EvmError::ErrorParameter(Some("'libraries' cannot be more than 10".to_owned())));
+        }
+
+        let runs = request.runs.to_string();
+        let license_type = request.license_type.map(|l| l.to_string());
+        let library_keys: Vec<(String, String)> = (1..=request.libraries.len())
+            .map(|i| (format!("libraryname{}", i), format!("libraryaddress{}", i)))
+            .collect();
+
+        let mut params = vec![
+            ("module", "contract"),
+            ("action", "verifysourcecode"),
+            ("contractaddress", request.contract_address.as_str()),
+            ("sourceCode", request.source_code.as_str()),
+            ("codeformat", request.code_format.as_str()),
+            ("contractname", request.contract_name.as_str()),
+            ("compilerversion", request.compiler_version.as_str()),
+            ("optimizationUsed", if request.optimization_used { "1" } else { "0" }),
+            ("runs", runs.as_str()),
+            // NOTE: misspelled as expected by API
+            ("constructorArguements", request.constructor_arguments.trim_start_matches("0x")),
+        ];
+        if let Some(ref evm_version) = request.evm_version {
+            params.push(("evmversion", evm_version.as_str()));
+        }
+        if let Some(ref license_type) = license_type {
+            params.push(("licenseType", license_type.as_str()));
+        }
+        for ((name_key, address_key), (name, address)) in library_keys.iter().zip(request.libraries.iter()) {
+            params.push((name_key.as_str(), name.as_str()));
+            params.push((address_key.as_str(), address.as_str()));
+        }
+
+        let json = http::post_form::<EvmContractVerificationResponse>(ctx, &params)?;
+        Contracts::verification_guid_from_response(json)
+    }
+
+    /// Check status of source code verification.
+    ///
+    /// # Arguments
+    /// * `ctx` - Context
+    /// * `guid` - GUID as returned from `verify_source_code()`
+    pub fn check_verify_status(&self, ctx: &Context, guid: &str) -> Result<EvmContractVerificationStatus, EvmError> {
+        self.get_verification_status(ctx, "checkverifystatus", guid)
+    }
+
+    /// Poll status of source code verification until it is no longer pending.
+    /// Return `Pending` if it is still pending after `max_attempts`.
+    ///
+    /// # Arguments
+    /// * `ctx` - Context
+    /// * `guid` - GUID as returned from `verify_source_code()`
+    /// * `interval` - duration to wait before each check
+    /// * `max_attempts` - maximum number of checks
+    pub fn wait_for_verify_status(&self, ctx: &Context, guid: &str, interval: std::time::Duration, max_attempts: u32) -> Result<EvmContractVerificationStatus, EvmError> {
+        Contracts::poll_verification_status(interval, max_attempts, || self.check_verify_status(ctx, guid))
+    }
+
+    /// Submit proxy contract for verification to link it with its
+    /// implementation. Return GUID to check its status with
+    /// `check_proxy_verification()`, or `wait_for_proxy_verification()`.
+    ///
+    /// # Arguments
+    /// * `ctx` - Context
+    /// * `address` - proxy contract address
+    /// * `expected_implementation` - implementation address expected to be found, or `None` to accept whatever found
+    pub fn verify_proxy_contract(&self, ctx: &Context, address: &str, expected_implementation: Option<&str>) -> Result<String, EvmError> {
+        let mut params = vec![
+            ("module", "contract"),
+            ("action", "verifyproxycontract"),
+            ("address", address),
+        ];
+        if let Some(expected_implementation) = expected_implementation {
+            params.push(("expectedimplementation", expected_implementation));
+        }
+
+        let json = http::post_form::<EvmContractVerificationResponse>(ctx, &params)?;
+        Contracts::verification_guid_from_response(json)
+    }
+
+    /// Check status of proxy contract verification.
+    ///
+    /// # Arguments
+    /// * `ctx` - Context
+    /// * `guid` - GUID as returned from `verify_proxy_contract()`
+    pub fn check_proxy_verification(&self, ctx: &Context, guid: &str) -> Result<EvmContractVerificationStatus, EvmError> {
+        self.get_verification_status(ctx, "checkproxyverification", guid)
+    }
+
+    /// Poll status of proxy contract verification until it is no longer
+    /// pending. Return `Pending` if it is still pending after `max_attempts`.
+    ///
+    /// # Arguments
+    /// * `ctx` - Context
+    /// * `guid` - GUID as returned from `verify_proxy_contract()`
+    /// * `interval` - duration to wait before each check
+    /// * `max_attempts` - maximum number of checks
+    pub fn wait_for_proxy_verification(&self, ctx: &Context, guid: &str, interval: std::time::Duration, max_attempts: u32) -> Result<EvmContractVerificationStatus, EvmError> {
+        Contracts::poll_verification_status(interval, max_attempts, || self.check_proxy_verification(ctx, guid))
+    }
+
+    /// Split contract's metadata as returned from API into source files along
+    /// with its compiler settings.
+    ///
+    /// Language is detected from compiler version first e.g. `vyper:0.3.7`,
+    /// then from `language` field of Standard JSON input.
+    ///
+    /// # Arguments
+    /// * `contract` - contract's metadata as returned from API
+    pub fn split_verified_source(mut contract: EvmContractSourceCode) -> Result<EvmContractVerifiedSource, EvmError> {
+        let input = Contracts::parse_source_code(&contract.source_code)?;
+        let language = Contracts::detect_language(&contract, input.as_ref());
+        contract.language = language.clone();
+
+        match input {
+            Some(input) => Ok(EvmContractVerifiedSource {
+                language,
+                is_standard_json: true,
+                sources: input.sources,
+                interfaces: input.interfaces,
+                settings: input.settings,
+                contract,
+            }),
+            None => {
+                Ok(EvmContractVerifiedSource {
+                    is_standard_json: false,
+                    sources: vec![EvmContractSourceFile {
+                        path: format!("{}.{}", contract.contract_name, language.file_extension()),
+                        content: contract.source_code.clone(),
+                    }],
+                    interfaces: Vec::new(),
+                    settings: None,
+                    language,
+                    contract,
+                })
+            },
+        }
+    }
+
+    /// Internal function supporting to detect language of contract's source
+    /// code. Vyper is detected from compiler version as its Standard JSON
+    /// input might not have `language` field, otherwise `language` field of
+    /// Standard JSON input is used. Plain source code is Solidity.
+    ///
+    /// # Arguments
+    /// * `contract` - contract's metadata
+    /// * `input` - source code parsed by `parse_source_code()`
+    pub(crate) fn detect_language(contract: &EvmContractSourceCode, input: Option<&EvmContractStandardJsonInput>) -> EvmContractLanguage {
+        match contract.parsed_compiler_version() {
+            Ok(ref compiler) if compiler.kind == EvmCompilerKind::Vyper => EvmContractLanguage::Vyper,
+            _ => match input {
+                Some(input) => EvmContractLanguage::parse(&input.language),
+                None => EvmContractLanguage::Solidity,
+            },
+        }
+    }
+
+    /// Parse `SourceCode` field as returned from API.
+    /// See
+    /// https://docs.soliditylang.org/en/latest/using-the-compiler.html#input-description,
+    /// and https://docs.vyperlang.org/en/stable/compiling-a-contract.html#input-json-description
+    ///
+    /// It can be in one of the following forms
+    /// * plain source code of a single file
+    /// * JSON map of source path to object with `content` field
+    /// * Standard JSON input, which API wraps with an extra pair of braces i.e. `{{ ... }}`
+    ///
+    /// Return `Ok(None)` for plain source code.
+    ///
+    /// # Arguments
+    /// * `source_code` - text as of `SourceCode` field
+    pub fn parse_source_code(source_code: &str) -> Result<Option<EvmContractStandardJsonInput>, EvmError> {
+        let text = source_code.trim();
+        if !text.starts_with('{') {
+            return Ok(None);
+        }
+
+        // strip the extra pair of braces wrapping Standard JSON input
+        let text = match text.strip_prefix("{{").and_then(|t| t.strip_suffix("}}")) {
+            Some(inner) => format!("{{{}}}", inner),
+            None => text.to_owned(),
+        };
+
+        // map of source files has no `sources` field at the top level
+        #[derive(serde::Deserialize)]
+        struct Probe {
+            sources: Option<serde::de::IgnoredAny>,
+        }
+
+        let to_err = |e: serde_json::Error| EvmError::ErrorJsonParsing(Some(format!("source code in JSON format; err={}", e)));
+        let probe = serde_json::from_str::<Probe>(&text).map_err(to_err)?;
+
+        // NOTE: deserialize directly from text instead of via `serde_json::Value`
+        // in order to keep the order of source files
+        if probe.sources.is_some() {
+            serde_json::from_str::<EvmContractStandardJsonInput>(&text).map(Some).map_err(to_err)
+        }
+        else {
+            let mut deserializer = serde_json::Deserializer::from_str(&text);
+            let sources = de_sources_map_to_vec_source_file(&mut deserializer).map_err(to_err)?;
+            Ok(Some(EvmContractStandardJsonInput {
+                language: "Solidity".to_owned(),
+                sources,
+                interfaces: Vec::new(),
+                settings: None,
+            }))
+        }
+    }
+
+    /// Internal function supporting to get contract's metadata records as
+    /// returned from API. Returned vector is guaranteed to be non-empty.
+    ///
+    /// # Arguments
+    /// * `ctx` - Context
+    /// * `address` - contract address to get verified source code
+    fn get_source_code_records(&self, ctx: &Context, address: &str) -> Result<Vec<EvmContractSourceCode>, EvmError> {
         let raw_url_str = format!("{}/api?module=contract&action=getsourcecode&address={address}&apikey={api_key}", Context::get_prefix_url(ctx.chain), address=address, api_key=ctx.api_key);
 
         let url = match Url::parse(&raw_url_str) {
@@ -143,116 +591,7 @@ impl Contracts {
                 }
 
                 match res.json::<EvmContractSourceCodeResponse>() {
-                    Ok(json) => {
-                        if json.status == "1" {
-                            match json.result {
-                                EvmContractSourceCodeResult::Success(mut contracts) => {
-                                    if contracts.is_empty() {
-                                        return Err(EvmError::ErrorApiResponse(format!("source code is empty")));
-                                    }
-
-                                    // this one is not that necessary for ABI, but
-                                    // it happens for code. So ensure it's clean
-                                    // as well for ABI.
-                                    contracts[0].abi = Contracts::replace_literal_crlf_with_char_codes(&contracts[0].abi);
-                                    // Clean the text e.g. \ for its abi and code
-                                    // the same way as contract ABI API would do.
-                                    // With that, the output string is ready
-                                    // to be piped and viewed by text editor
-                                    // in which newlines will be taken into effect.
-                                    // NOTE: do this after CR/LF cleaning
-                                    contracts[0].abi = str::replace(&contracts[0].abi, "\\", "");
-
-                                    // there can be a chance that source code is in
-                                    // json format. It allows clear multiple source
-                                    // files that we can better utilize and return
-                                    // as such instead of combined it altogether.
-                                    //
-                                    // with this way, we will replace the items
-                                    // as held by contracts to what we will find
-                                    // from multiple files here
-                                    let mut additional_vec: Vec<EvmContractSourceCode> = Vec::new();    // create a new addtional vector just to avoid borrow issue
-                                                                                                        // within the loop
-                                    if Contracts::is_content_in_json_format(&contracts[0].source_code) {
-                                        let regex = Regex::new(r#""(.+)":\s*\{\s*.*?"content":\s*"(.+)""#).unwrap();
-                                        for cap in regex.captures_iter(&contracts[0].source_code) {
-                                            // NOTE: do this in order to not interfere
-                                            // with CR/LF cleaning
-                                            let mut cleaned_contract_code = Contracts::replace_literal_crlf_with_char_codes(&cap[2]);
-                                            cleaned_contract_code = str::replace(&cleaned_contract_code, "\\", "");
-
-                                            additional_vec.push(EvmContractSourceCode {
-                                                source_code: cleaned_contract_code,
-                                                abi: contracts[0].abi.clone(),
-                                                contract_name: cap[1].to_owned(),
-                                                compiler_version: contracts[0].compiler_version.clone(),
-                                                optimization_used: contracts[0].optimization_used.clone(),
-                                                runs: contracts[0].runs.clone(),
-                                                constructor_arguments: contracts[0].constructor_arguments.clone(),
-                                                evm_version: contracts[0].evm_version.clone(),
-                                                library: contracts[0].library.clone(),
-                                                license_type: contracts[0].license_type.clone(),
-                                                proxy: contracts[0].proxy.clone(),
-                                                implementation: contracts[0].implementation.clone(),
-                                                swarm_source: contracts[0].swarm_source.clone(),
-                                            });
-                                        }
-                                        contracts.append(&mut additional_vec);
-                                        return Ok((contracts, true));
-                                    }
-
-                                    // also check whether we made query for un-verified source code
-                                    // in which response back from server still
-                                    // has `status` field as "1". We need to check
-                                    // against `ABI` field for exact string of
-                                    // "Contract source code not verified".
-                                    //
-                                    // We treat this as an error as it doesn't make
-                                    // sense to return with no meta-detail information
-                                    // at all for non-verified source code even
-                                    // though its `status` is "1".
-                                    //
-                                    // NOTE: we only have interest towards the first
-                                    // item of `contracts` as this is the way
-                                    // API returns to us although there are
-                                    // multiple of source files uploaded.
-                                    if contracts.first().unwrap().abi == "Contract source code not verified" {
-                                        return Err(EvmError::ErrorApiResponse(format!("made query to un-verified contract source code")));
-                                    }
-                                    else {
-                                        // NOTE: do this in order to not interfere
-                                        // with CR/LF cleaning
-                                        contracts[0].source_code = Contracts::replace_literal_crlf_with_char_codes(&contracts[0].source_code);
-                                        contracts[0].source_code = str::replace(&contracts[0].source_code, "\\", "");
-
-                                        return Ok((contracts, false));
-                                    }
-                                },
-                                EvmContractSourceCodeResult::Failed(result_msg) => {
-                                    return Err(EvmError::ErrorApiResponse(format!("un-expected error for success case ({msg})", msg=result_msg)));
-                                },
-                            }
-                        }
-                        else {
-                            // safely get text from "result" field
-                            // this will ensure that the type of `json.result` is
-                            // actually EvmContractSourceCodeResult which is
-                            // the failed case.
-                            let result_text = match json.result {
-                                EvmContractSourceCodeResult::Failed(txt) => Some(txt),
-                                _ => None,
-                            };
-
-                            match result_text {
-                                Some(txt) => {
-                                    return Err(EvmError::ErrorApiResponse(format!("message:{}, result:{}", json.message, txt)));
-                                },
-                                None => {
-                                    return Err(EvmError::ErrorApiResponse(format!("message:{}", json.message)));
-                                },
-                            }
-                        }
-                    },
+                    Ok(json) => Contracts::source_code_records_from_response(json),
                     Err(e) => {
                         eprintln!("{:?}", e);
                         return Err(EvmError::ErrorJsonParsing(None));
@@ -265,4 +604,242 @@ impl Contracts {
             }
         }
     }
+
+    /// Internal function supporting to get contract creation of a single batch
+    /// of addresses.
+    ///
+    /// # Arguments
+    /// * `ctx` - Context
+    /// * `addresses` - contract addresses, at most 5 addresses
+    fn get_contract_creation_batch(&self, ctx: &Context, addresses: &[&str]) -> Result<Vec<EvmContractCreation>, EvmError> {
+        let raw_url_str = format!("{}/api?module=contract&action=getcontractcreation&contractaddresses={addresses_str}&apikey={api_key}", Context::get_prefix_url(ctx.chain), addresses_str=addresses.join(","), api_key=ctx.api_key);
+
+        let url = match Url::parse(&raw_url_str) {
+            Ok(res) => res,
+            Err(_) => return Err(EvmError::ErrorInternalUrlParsing),
+        };
+
+        let request = match isahc::Request::get(url.as_str())
+            .version_negotiation(isahc::config::VersionNegotiation::http2())
+            .body(()) {
+            Ok(res) => res,
+            Err(e) => return Err(EvmError::ErrorInternalGeneric(Some(format!("Error creating a HTTP request; err={}", e)))),
+        };
+
+        match isahc::send(request) {
+            Ok(mut res) => {
+                // early return for non-200 HTTP returned code
+                if res.status() != 200 {
+                    return Err(EvmError::ErrorApiResponse(format!("Error API response, with HTTP {code} returned", code=res.status().as_str())));
+                }
+
+                match res.json::<EvmContractCreationResponse>() {
+                    Ok(json) => Contracts::contract_creation_from_response(json),
+                    Err(e) => {
+                        eprintln!("{:?}", e);
+                        Err(EvmError::ErrorJsonParsing(None))
+                    }
+                }
+            },
+            Err(e) => Err(EvmError::ErrorSendingHttpRequest(Some(format!("{}", e)))),
+        }
+    }
+
+    /// Internal function supporting to get contract creation from API response.
+    /// "No data found" is treated as empty result.
+    ///
+    /// # Arguments
+    /// * `json` - API response
+    pub(crate) fn contract_creation_from_response(json: EvmContractCreationResponse) -> Result<Vec<EvmContractCreation>, EvmError> {
+        if json.status != "1" && json.message.starts_with("No data found") {
+            return Ok(Vec::new());
+        }
+
+        match json.result {
+            Some(EvmContractCreationResult::Success(creations)) if json.status == "1" => Ok(creations),
+            Some(EvmContractCreationResult::Failed(txt)) => Err(EvmError::ErrorApiResponse(format!("message:{}, result:{}", json.message, txt))),
+            _ => Err(EvmError::ErrorApiResponse(format!("message:{}", json.message))),
+        }
+    }
+
+    /// Internal function supporting to check status of verification.
+    ///
+    /// # Arguments
+    /// * `ctx` - Context
+    /// * `action` - either `checkverifystatus`, or `checkproxyverification`
+    /// * `guid` - GUID as returned from submission
+    fn get_verification_status(&self, ctx: &Context, action: &str, guid: &str) -> Result<EvmContractVerificationStatus, EvmError> {
+        let raw_url_str = format!("{}/api?module=contract&action={action}&guid={guid}&apikey={api_key}", Context::get_prefix_url(ctx.chain), action=action, guid=guid, api_key=ctx.api_key);
+
+        let url = match Url::parse(&raw_url_str) {
+            Ok(res) => res,
+            Err(_) => return Err(EvmError::ErrorInternalUrlParsing),
+        };
+
+        let request = match isahc::Request::get(url.as_str())
+            .version_negotiation(isahc::config::VersionNegotiation::http2())
+            .body(()) {
+            Ok(res) => res,
+            Err(e) => return Err(EvmError::ErrorInternalGeneric(Some(format!("Error creating a HTTP request; err={}", e)))),
+        };
+
+        match isahc::send(request) {
+            Ok(mut res) => {
+                // early return for non-200 HTTP returned code
+                if res.status() != 200 {
+                    return Err(EvmError::ErrorApiResponse(format!("Error API response, with HTTP {code} returned", code=res.status().as_str())));
+                }
+
+                match res.json::<EvmContractVerificationResponse>() {
+                    Ok(json) => Contracts::verification_status_from_response(json),
+                    Err(e) => {
+                        eprintln!("{:?}", e);
+                        Err(EvmError::ErrorJsonParsing(None))
+                    }
+                }
+            },
+            Err(e) => Err(EvmError::ErrorSendingHttpRequest(Some(format!("{}", e)))),
+        }
+    }
+
+    /// Internal function supporting to poll verification status with `check`
+    /// until it is no longer pending.
+    fn poll_verification_status<F>(interval: std::time::Duration, max_attempts: u32, mut check: F) -> Result<EvmContractVerificationStatus, EvmError>
+    where
+        F: FnMut() -> Result<EvmContractVerificationStatus, EvmError>
+    {
+        for _ in 0..max_attempts {
+            std::thread::sleep(interval);
+            match check()? {
+                EvmContractVerificationStatus::Pending => continue,
+                status => return Ok(status),
+            }
+        }
+        Ok(EvmContractVerificationStatus::Pending)
+    }
+
+    /// Internal function supporting to get GUID from response of submitting
+    /// verification.
+    ///
+    /// # Arguments
+    /// * `json` - API response
+    pub(crate) fn verification_guid_from_response(json: EvmContractVerificationResponse) -> Result<String, EvmError> {
+        if json.status == "1" {
+            Ok(json.result)
+        }
+        else {
+            Err(EvmError::ErrorApiResponse(format!("message:{}, result:{}", json.message, json.result)))
+        }
+    }
+
+    /// Internal function supporting to get verification status from API
+    /// response. Failure to verify is not an error, but `Fail` status. Error
+    /// is only returned for failure of API itself e.g. invalid API key.
+    ///
+    /// # Arguments
+    /// * `json` - API response
+    pub(crate) fn verification_status_from_response(json: EvmContractVerificationResponse) -> Result<EvmContractVerificationStatus, EvmError> {
+        let result = json.result.trim();
+        if result.starts_with("Pending") || result.starts_with("In progress") {
+            Ok(EvmContractVerificationStatus::Pending)
+        }
+        else if json.status == "1" || result.eq_ignore_ascii_case("Already Verified") {
+            Ok(EvmContractVerificationStatus::Pass)
+        }
+        else if result.contains("API Key") || result.contains("rate limit") || result.contains("Unable to locate GUID") {
+            Err(EvmError::ErrorApiResponse(format!("message:{}, result:{}", json.message, result)))
+        }
+        else {
+            Ok(EvmContractVerificationStatus::Fail(result.to_owned()))
+        }
+    }
+
+    /// Internal function supporting to get contract ABI in JSON text from
+    /// API response.
+    ///
+    /// `result` field is kept as-is. It is already unescaped exactly once by
+    /// JSON parsing of API response, so it is exactly the same as verified.
+    ///
+    /// # Arguments
+    /// * `json` - API response
+    pub(crate) fn abi_text_from_response(json: EvmContractABIResponse) -> Result<String, EvmError> {
+        if json.status == "1" {
+            Ok(json.result)
+        }
+        else {
+            Err(EvmError::ErrorApiResponse(format!("message:{}, result:{}", json.message, json.result)))
+        }
+    }
+
+    /// Internal function supporting to check whether error is from querying
+    /// contract without verified source code, as opposed to other errors e.g.
+    /// rate limit, or invalid API key.
+    ///
+    /// # Arguments
+    /// * `err` - error as returned from getting ABI
+    pub(crate) fn is_source_code_not_verified(err: &EvmError) -> bool {
+        match err {
+            EvmError::ErrorApiResponse(msg) => msg.contains(SOURCE_CODE_NOT_VERIFIED),
+            _ => false,
+        }
+    }
+
+    /// Internal function supporting to get contract's metadata records from
+    /// API response. Returned vector is guaranteed to be non-empty.
+    ///
+    /// Similarly to `abi_text_from_response()`, all text fields are kept as-is.
+    ///
+    /// # Arguments
+    /// * `json` - API response
+    pub(crate) fn source_code_records_from_response(json: EvmContractSourceCodeResponse) -> Result<Vec<EvmContractSourceCode>, EvmError> {
+        if json.status == "1" {
+            match json.result {
+                EvmContractSourceCodeResult::Success(contracts) => {
+                    if contracts.is_empty() {
+                        return Err(EvmError::ErrorApiResponse("source code is empty".to_owned()));
+                    }
+
+                    // also check whether we made query for un-verified source code
+                    // in which response back from server still
+                    // has `status` field as "1". We need to check
+                    // against `ABI` field for exact string of
+                    // "Contract source code not verified".
+                    //
+                    // We treat this as an error as it doesn't make
+                    // sense to return with no meta-detail information
+                    // at all for non-verified source code even
+                    // though its `status` is "1".
+                    //
+                    // NOTE: we only have interest towards the first
+                    // item of `contracts` as this is the way
+                    // API returns to us although there are
+                    // multiple of source files uploaded.
+                    if contracts.first().unwrap().abi == SOURCE_CODE_NOT_VERIFIED {
+                        Err(EvmError::ErrorApiResponse("made query to un-verified contract source code".to_owned()))
+                    }
+                    else {
+                        Ok(contracts)
+                    }
+                },
+                EvmContractSourceCodeResult::Failed(result_msg) => {
+                    Err(EvmError::ErrorApiResponse(format!("un-expected error for success case ({msg})", msg=result_msg)))
+                },
+            }
+        }
+        else {
+            // safely get text from "result" field
+            // this will ensure that the type of `json.result` is
+            // actually EvmContractSourceCodeResult which is
+            // the failed case.
+            let result_text = match json.result {
+                EvmContractSourceCodeResult::Failed(txt) => Some(txt),
+                _ => None,
+            };
+
+            match result_text {
+                Some(txt) => Err(EvmError::ErrorApiResponse(format!("message:{}, result:{}", json.message, txt))),
+                None => Err(EvmError::ErrorApiResponse(format!("message:{}", json.message))),
+            }
+        }
+    }
 }
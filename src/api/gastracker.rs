@@ -0,0 +1,75 @@
+use crate::prelude::*;
+use crate::types::evm_types::*;
+use crate::api::http;
+use crate::environ::Context;
+
+/// Gas Tracker namespace containing related APIs about gas price
+pub struct GasTracker;
+
+impl GasTracker {
+    /// Get gas oracle i.e. safe, proposed, and fast gas prices. Fields which
+    /// are not returned by the chain as set in `ctx` are left empty.
+    ///
+    /// # Arguments
+    /// * `ctx` - context instance
+    pub fn get_gas_oracle(&self, ctx: &Context) -> Result<EvmGasOracle, EvmError> {
+        match ctx.chain {
+            ChainType::BSC => GasTracker::gas_oracle_from_response(self.request::<EvmGasOracleResponse<EvmGasOracle_BSC>>(ctx, "gasoracle", "")?),
+            ChainType::Ethereum => GasTracker::gas_oracle_from_response(self.request::<EvmGasOracleResponse<EvmGasOracle_Ethereum>>(ctx, "gasoracle", "")?),
+            ChainType::Polygon => GasTracker::gas_oracle_from_response(self.request::<EvmGasOracleResponse<EvmGasOracle_Polygon>>(ctx, "gasoracle", "")?),
+        }
+    }
+
+    /// Get estimated time in seconds for a transaction to be confirmed.
+    ///
+    /// # Arguments
+    /// * `ctx` - context instance
+    /// * `gas_price` - gas price in Wei
+    pub fn get_confirmation_time_estimate(&self, ctx: &Context, gas_price: U256) -> Result<u64, EvmError> {
+        let json = self.request::<EvmGasEstimateResponse>(ctx, "gasestimate", &format!("&gasprice={}", gas_price))?;
+        GasTracker::confirmation_time_from_response(json)
+    }
+
+    /// Internal function supporting to make request to gas tracker module,
+    /// then parse response as `T`.
+    ///
+    /// # Arguments
+    /// * `ctx` - context instance
+    /// * `action` - API action e.g. `gasoracle`
+    /// * `params` - query parameters, each one prefixed with `&`
+    fn request<T>(&self, ctx: &Context, action: &str, params: &str) -> Result<T, EvmError>
+    where
+        T: serde::de::DeserializeOwned
+    {
+        http::get_json(ctx, &format!("module=gastracker&action={}{}", action, params))
+    }
+
+    /// Internal function supporting to get gas oracle from API response, then
+    /// map chain specific structure into `EvmGasOracle`.
+    ///
+    /// # Arguments
+    /// * `json` - API response
+    pub(crate) fn gas_oracle_from_response<T>(json: EvmGasOracleResponse<T>) -> Result<EvmGasOracle, EvmError>
+    where
+        T: Into<EvmGasOracle>
+    {
+        match json.result {
+            EvmGasOracleResult::Success(oracle) if json.status == "1" => Ok(oracle.into()),
+            EvmGasOracleResult::Failed(txt) => Err(EvmError::ErrorApiResponse(format!("message:{}, result:{}", json.message, txt))),
+            _ => Err(EvmError::ErrorApiResponse(format!("message:{}", json.message))),
+        }
+    }
+
+    /// Internal function supporting to get estimated confirmation time from
+    /// API response.
+    ///
+    /// # Arguments
+    /// * `json` - API response
+    pub(crate) fn confirmation_time_from_response(json: EvmGasEstimateResponse) -> Result<u64, EvmError> {
+        if json.status != "1" {
+            return Err(EvmError::ErrorApiResponse(format!("message:{}, result:{}", json.message, json.result)));
+        }
+        json.result.parse::<u64>()
+            .map_err(|e| EvmError::ErrorJsonParsing(Some(format!("estimated time '{}'; err={}", json.result, e))))
+    }
+}
@@ -0,0 +1,133 @@
+use crate::prelude::*;
+use crate::environ::Context;
+
+use isahc::prelude::*;
+use url::Url;
+
+/// Send GET request with `query` to API endpoint of chain as set in `ctx`,
+/// then parse its response as `T`. API key is always appended to the query
+/// so it doesn't need to be in `query`.
+///
+/// # Arguments
+/// * `ctx` - context instance
+/// * `query` - query string without leading `?` e.g. `module=block&action=getblockreward&blockno=1`
+pub(crate) fn get_json<T>(ctx: &Context, query: &str) -> Result<T, EvmError>
+where
+    T: serde::de::DeserializeOwned
+{
+    let raw_url_str = format!("{}/api?{query}&apikey={api_key}", Context::get_prefix_url(ctx.chain), query=query, api_key=ctx.api_key);
+
+    let url = match Url::parse(&raw_url_str) {
+        Ok(res) => res,
+        Err(_) => return Err(EvmError::ErrorInternalUrlParsing),
+    };
+
+    let request = match isahc::Request::get(url.as_str())
+        .version_negotiation(isahc::config::VersionNegotiation::http2())
+        .body(()) {
+        Ok(res) => res,
+        Err(e) => return Err(EvmError::ErrorInternalGeneric(Some(format!("Error creating a HTTP request; err={}", e)))),
+    };
+
+    match isahc::send(request) {
+        Ok(mut res) => {
+            // early return for non-200 HTTP returned code
+            if res.status() != 200 {
+                return Err(EvmError::ErrorApiResponse(format!("Error API response, with HTTP {code} returned", code=res.status().as_str())));
+            }
+
+            match res.json::<T>() {
+                Ok(json) => Ok(json),
+                Err(e) => {
+                    eprintln!("{:?}", e);
+                    Err(EvmError::ErrorJsonParsing(None))
+                }
+            }
+        },
+        Err(e) => Err(EvmError::ErrorSendingHttpRequest(Some(format!("{}", e)))),
+    }
+}
+
+/// Send POST request with form-urlencoded `params` to API endpoint of chain as
+/// set in `ctx`, then parse its response as `T`. API key is always included
+/// in the form so it doesn't need to be in `params`.
+///
+/// # Arguments
+/// * `ctx` - context instance
+/// * `params` - form fields in order
+pub(crate) fn post_form<T>(ctx: &Context, params: &[(&str, &str)]) -> Result<T, EvmError>
+where
+    T: serde::de::DeserializeOwned
+{
+    let raw_url_str = format!("{}/api", Context::get_prefix_url(ctx.chain));
+
+    let url = match Url::parse(&raw_url_str) {
+        Ok(res) => res,
+        Err(_) => return Err(EvmError::ErrorInternalUrlParsing),
+    };
+
+    let body = url::form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(params.iter())
+        .append_pair("apikey", &ctx.api_key)
+        .finish();
+
+    let request = match isahc::Request::post(url.as_str())
+        .header("Content-Type", "application/x-www-form-urlencoded")
+        .version_negotiation(isahc::config::VersionNegotiation::http2())
+        .body(body) {
+        Ok(res) => res,
+        Err(e) => return Err(EvmError::ErrorInternalGeneric(Some(format!("Error creating a HTTP request; err={}", e)))),
+    };
+
+    match isahc::send(request) {
+        Ok(mut res) => {
+            // early return for non-200 HTTP returned code
+            if res.status() != 200 {
+                return Err(EvmError::ErrorApiResponse(format!("Error API response, with HTTP {code} returned", code=res.status().as_str())));
+            }
+
+            match res.json::<T>() {
+                Ok(json) => Ok(json),
+                Err(e) => {
+                    eprintln!("{:?}", e);
+                    Err(EvmError::ErrorJsonParsing(None))
+                }
+            }
+        },
+        Err(e) => Err(EvmError::ErrorSendingHttpRequest(Some(format!("{}", e)))),
+    }
+}
+
+/// Limiter spacing out requests so that no more than `requests_per_second`
+/// requests are made in a second. It blocks the current thread as needed.
+pub(crate) struct RateLimiter {
+    interval: std::time::Duration,
+    last_request: Option<std::time::Instant>,
+}
+
+impl RateLimiter {
+    /// Create a limiter. Return `Err` if `requests_per_second` is 0.
+    ///
+    /// # Arguments
+    /// * `requests_per_second` - rate limit of API key e.g. 5 for free API key
+    pub(crate) fn create(requests_per_second: u32) -> Result<RateLimiter, EvmError> {
+        if requests_per_second == 0 {
+            return Err(EvmError::ErrorParameter(Some("'requests_per_second' needs to be more than 0".to_owned())));
+        }
+        Ok(RateLimiter {
+            interval: std::time::Duration::from_secs(1) / requests_per_second,
+            last_request: None,
+        })
+    }
+
+    /// Wait until the next request can be made.
+    pub(crate) fn wait(&mut self) {
+        if let Some(last) = self.last_request {
+            let elapsed = last.elapsed();
+            if elapsed < self.interval {
+                std::thread::sleep(self.interval - elapsed);
+            }
+        }
+        self.last_request = Some(std::time::Instant::now());
+    }
+}
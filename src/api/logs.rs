@@ -0,0 +1,145 @@
+use crate::prelude::*;
+use crate::types::evm_types::*;
+use crate::api::http;
+use crate::environ::Context;
+
+/// Maximum number of logs per page as allowed by API
+const OFFSET: usize = 1000;
+
+/// Maximum number of logs reachable by paging i.e. page * offset must be less
+/// than or equal to this number
+const WINDOW_LIMIT: usize = 10_000;
+
+/// Logs namespace containing related APIs about event logs
+pub struct Logs;
+
+impl Logs {
+    /// Get event logs matching filter. All pages are fetched.
+    ///
+    /// Paging of API can only reach the first 10,000 logs, so when it is
+    /// reached, the next window of pages starts at the block of the last log
+    /// instead. Thus there is no limit on the number of logs as long as a
+    /// single block doesn't have more than 10,000 matching logs, otherwise
+    /// `ErrorApiResponse` naming the block is returned so that filter can be
+    /// narrowed down.
+    ///
+    /// # Arguments
+    /// * `ctx` - context instance
+    /// * `filter` - filter of logs, at least either address, or a topic needs
+    ///   to be set
+    pub fn get_logs(&self, ctx: &Context, filter: &EvmLogFilter) -> Result<Vec<EvmLog>, EvmError> {
+        let params = Logs::filter_params(filter)?;
+        Logs::paginate(filter.from_block, |from_block, page_number| {
+            let json = self.get_logs_page(ctx, from_block, &params, page_number)?;
+            Logs::logs_from_response(json)
+        })
+    }
+
+    /// Internal function supporting to get a page of logs.
+    ///
+    /// # Arguments
+    /// * `ctx` - context instance
+    /// * `from_block` - start block
+    /// * `params` - query parameters of filter other than `fromBlock`
+    /// * `page_number` - page number starting from 1
+    fn get_logs_page(&self, ctx: &Context, from_block: u64, params: &str, page_number: usize) -> Result<EvmLogsResponse, EvmError> {
+        http::get_json(ctx, &format!("module=logs&action=getLogs&fromBlock={}{}&page={}&offset={}", from_block, params, page_number, OFFSET))
+    }
+
+    /// Internal function supporting to create query parameters of filter
+    /// other than `fromBlock` which changes across windows.
+    ///
+    /// # Arguments
+    /// * `filter` - filter of logs
+    pub(crate) fn filter_params(filter: &EvmLogFilter) -> Result<String, EvmError> {
+        if filter.address.is_none() && filter.topics.iter().all(|t| t.is_none()) {
+            return Err(EvmError::ErrorParameter(Some("either address, or a topic needs to be set".to_owned())));
+        }
+        if let Some(to_block) = filter.to_block {
+            if to_block < filter.from_block {
+                return Err(EvmError::ErrorParameter(Some("'to_block' cannot be less than 'from_block'".to_owned())));
+            }
+        }
+
+        let mut params = match filter.to_block {
+            Some(to_block) => format!("&toBlock={}", to_block),
+            None => "&toBlock=latest".to_owned(),
+        };
+        if let Some(ref address) = filter.address {
+            params.push_str(&format!("&address={}", address));
+        }
+        for (i, topic) in filter.topics.iter().enumerate() {
+            if let Some(topic) = topic {
+                params.push_str(&format!("&topic{}={}", i, topic));
+            }
+        }
+        for (x, y, operator) in filter.topic_operators.iter() {
+            if x >= y || *y >= filter.topics.len() || filter.topics[*x].is_none() || filter.topics[*y].is_none() {
+                return Err(EvmError::ErrorParameter(Some(format!("invalid operator between topic{} and topic{}", x, y))));
+            }
+            params.push_str(&format!("&topic{}_{}_opr={}", x, y, operator.as_str()));
+        }
+        Ok(params)
+    }
+
+    /// Internal function supporting to fetch all pages of logs starting from
+    /// `from_block`. Once page * offset reaches the window limit, pages start
+    /// over from the block of the last log, and logs of that block fetched so
+    /// far are dropped as they will be fetched again in full. Error is returned
+    /// if a single block has more logs than the window limit.
+    ///
+    /// # Arguments
+    /// * `from_block` - start block
+    /// * `fetch` - function to fetch a page given its start block, and page number
+    pub(crate) fn paginate<F>(from_block: u64, mut fetch: F) -> Result<Vec<EvmLog>, EvmError>
+    where
+        F: FnMut(u64, usize) -> Result<Vec<EvmLog>, EvmError>
+    {
+        let mut ret_logs: Vec<EvmLog> = Vec::new();
+        let mut from_block = from_block;
+        let mut page_number = 1usize;
+
+        loop {
+            if page_number * OFFSET > WINDOW_LIMIT {
+                let last_block = match ret_logs.last() {
+                    Some(log) => log.block_number,
+                    None => break,
+                };
+                if last_block <= from_block {
+                    return Err(EvmError::ErrorApiResponse(format!("block {} has more than {} matching logs, narrow down the filter", from_block, WINDOW_LIMIT)));
+                }
+
+                while ret_logs.last().map(|log| log.block_number == last_block).unwrap_or(false) {
+                    ret_logs.pop();
+                }
+                from_block = last_block;
+                page_number = 1;
+            }
+
+            let mut logs = fetch(from_block, page_number)?;
+            let is_last_page = logs.len() < OFFSET;
+            ret_logs.append(&mut logs);
+            if is_last_page {
+                break;
+            }
+            page_number += 1;
+        }
+
+        Ok(ret_logs)
+    }
+
+    /// Internal function supporting to get logs from API response. "No records
+    /// found" is treated as empty result.
+    ///
+    /// # Arguments
+    /// * `json` - API response
+    pub(crate) fn logs_from_response(json: EvmLogsResponse) -> Result<Vec<EvmLog>, EvmError> {
+        match json.result {
+            EvmLogsResult::Success(logs) if json.status == "1" => Ok(logs),
+            // exact text as returned when empty "result" is returned
+            _ if json.message == "No records found" => Ok(Vec::new()),
+            EvmLogsResult::Failed(txt) => Err(EvmError::ErrorApiResponse(format!("message:{}, result:{}", json.message, txt))),
+            _ => Err(EvmError::ErrorApiResponse(format!("message:{}", json.message))),
+        }
+    }
+}
@@ -0,0 +1,334 @@
+use crate::prelude::*;
+use crate::types::evm_types::*;
+use crate::types::abi_types::{EvmAbiValue, EvmAbiNamedValue};
+use crate::deserialize::{parse_hex_quantity_u64, parse_hex_quantity_U256};
+use crate::abi::{encode, decode};
+use crate::api::http;
+use crate::environ::Context;
+
+/// Prefix of EIP-7702 delegation designator set as code of EOA
+const EIP7702_DELEGATION_PREFIX: [u8; 3] = [0xef, 0x01, 0x00];
+
+/// Proxy namespace containing Geth/Parity JSON-RPC APIs passed through API
+pub struct Proxy;
+
+impl Proxy {
+    /// Get the number of the most recent block.
+    ///
+    /// # Arguments
+    /// * `ctx` - context instance
+    pub fn get_block_number(&self, ctx: &Context) -> Result<u64, EvmError> {
+        let res = self.request::<String>(ctx, "eth_blockNumber", "")?;
+        parse_hex_quantity_u64(&res).map_err(|e| EvmError::ErrorJsonParsing(Some(e)))
+    }
+
+    /// Get block by its number with only hashes of its transactions.
+    /// Return `Ok(None)` if there is no such block.
+    ///
+    /// # Arguments
+    /// * `ctx` - context instance
+    /// * `block` - block parameter
+    pub fn get_block_by_number(&self, ctx: &Context, block: EvmBlockParameter) -> Result<Option<EvmRpcBlock<String>>, EvmError> {
+        self.request::<Option<EvmRpcBlock<String>>>(ctx, "eth_getBlockByNumber", &format!("&tag={}&boolean=false", block.as_tag()))
+    }
+
+    /// Get block by its number with full transactions.
+    /// Return `Ok(None)` if there is no such block.
+    ///
+    /// # Arguments
+    /// * `ctx` - context instance
+    /// * `block` - block parameter
+    pub fn get_block_by_number_with_transactions(&self, ctx: &Context, block: EvmBlockParameter) -> Result<Option<EvmRpcBlock<EvmRpcTransaction>>, EvmError> {
+        self.request::<Option<EvmRpcBlock<EvmRpcTransaction>>>(ctx, "eth_getBlockByNumber", &format!("&tag={}&boolean=true", block.as_tag()))
+    }
+
+    /// Get the number of transactions in a block.
+    /// Return `Ok(None)` if there is no such block.
+    ///
+    /// # Arguments
+    /// * `ctx` - context instance
+    /// * `block` - block parameter
+    pub fn get_block_transaction_count_by_number(&self, ctx: &Context, block: EvmBlockParameter) -> Result<Option<u64>, EvmError> {
+        match self.request::<Option<String>>(ctx, "eth_getBlockTransactionCountByNumber", &format!("&tag={}", block.as_tag()))? {
+            Some(count) => parse_hex_quantity_u64(&count).map(Some).map_err(|e| EvmError::ErrorJsonParsing(Some(e))),
+            None => Ok(None),
+        }
+    }
+
+    /// Get transaction by its hash.
+    /// Return `Ok(None)` if there is no such transaction.
+    ///
+    /// # Arguments
+    /// * `ctx` - context instance
+    /// * `tx_hash` - transaction hash
+    pub fn get_transaction_by_hash(&self, ctx: &Context, tx_hash: &str) -> Result<Option<EvmRpcTransaction>, EvmError> {
+        self.request::<Option<EvmRpcTransaction>>(ctx, "eth_getTransactionByHash", &format!("&txhash={}", tx_hash))
+    }
+
+    /// Get transaction by block number, and its index position in the block.
+    /// Return `Ok(None)` if there is no such transaction.
+    ///
+    /// # Arguments
+    /// * `ctx` - context instance
+    /// * `block` - block parameter
+    /// * `index` - index position of transaction in the block
+    pub fn get_transaction_by_block_number_and_index(&self, ctx: &Context, block: EvmBlockParameter, index: u64) -> Result<Option<EvmRpcTransaction>, EvmError> {
+        self.request::<Option<EvmRpcTransaction>>(ctx, "eth_getTransactionByBlockNumberAndIndex", &format!("&tag={}&index={:#x}", block.as_tag(), index))
+    }
+
+    /// Get receipt of a transaction including its logs.
+    /// Return `Ok(None)` if there is no such transaction, or it is pending.
+    ///
+    /// # Arguments
+    /// * `ctx` - context instance
+    /// * `tx_hash` - transaction hash
+    pub fn get_transaction_receipt(&self, ctx: &Context, tx_hash: &str) -> Result<Option<EvmRpcTransactionReceipt>, EvmError> {
+        self.request::<Option<EvmRpcTransactionReceipt>>(ctx, "eth_getTransactionReceipt", &format!("&txhash={}", tx_hash))
+    }
+
+    /// Get code deployed at an address. It is empty for EOA address.
+    ///
+    /// # Arguments
+    /// * `ctx` - context instance
+    /// * `address` - address to get code
+    /// * `block` - block parameter
+    pub fn get_code(&self, ctx: &Context, address: &str, block: EvmBlockParameter) -> Result<Vec<u8>, EvmError> {
+        let res = self.request::<String>(ctx, "eth_getCode", &format!("&address={}&tag={}", address, block.as_tag()))?;
+        decode::decode_hex(&res)
+    }
+
+    /// Whether there is code deployed at an address as of the latest block.
+    /// EOA delegated to a contract by EIP-7702 has delegation designator as
+    /// its code i.e. `0xef0100` followed by 20-byte address, and it is still
+    /// considered as EOA.
+    ///
+    /// NOTE: Contract under construction, or self-destructed has no code, thus
+    /// it is not considered as contract.
+    ///
+    /// # Arguments
+    /// * `ctx` - context instance
+    /// * `address` - address to check
+    pub fn is_contract(&self, ctx: &Context, address: &str) -> Result<bool, EvmError> {
+        Ok(Self::is_contract_code(&self.get_code(ctx, address, EvmBlockParameter::Latest)?))
+    }
+
+    /// Get value of a storage slot of contract.
+    ///
+    /// # Arguments
+    /// * `ctx` - context instance
+    /// * `address` - contract address
+    /// * `position` - storage slot
+    /// * `block` - block parameter
+    pub fn get_storage_at(&self, ctx: &Context, address: &str, position: U256, block: EvmBlockParameter) -> Result<H256, EvmError> {
+        let res = self.request::<String>(ctx, "eth_getStorageAt", &format!("&address={}&position={:#x}&tag={}", address, position, block.as_tag()))?;
+        Self::storage_value_from_hex(&res)
+    }
+
+    /// Get the number of transactions sent from an address i.e. its nonce.
+    ///
+    /// # Arguments
+    /// * `ctx` - context instance
+    /// * `address` - address to get its transaction count
+    /// * `block` - block parameter, use `Pending` to include pending transactions
+    pub fn get_transaction_count(&self, ctx: &Context, address: &str, block: EvmBlockParameter) -> Result<u64, EvmError> {
+        let res = self.request::<String>(ctx, "eth_getTransactionCount", &format!("&address={}&tag={}", address, block.as_tag()))?;
+        parse_hex_quantity_u64(&res).map_err(|e| EvmError::ErrorJsonParsing(Some(e)))
+    }
+
+    /// Execute a message call to contract without creating a transaction, then
+    /// return its raw return data.
+    ///
+    /// # Arguments
+    /// * `ctx` - context instance
+    /// * `to` - contract address to call
+    /// * `data` - ABI encoded call data
+    /// * `block` - block parameter, note that API might only support `Latest`
+    pub fn call(&self, ctx: &Context, to: &str, data: &[u8], block: EvmBlockParameter) -> Result<Vec<u8>, EvmError> {
+        let res = self.request::<String>(ctx, "eth_call", &format!("&to={}&data={}&tag={}", to, encode::encode_hex(data), block.as_tag()))?;
+        decode::decode_hex(&res)
+    }
+
+    /// Call a function of contract by its signature, then decode its return
+    /// data according to output types appended to the signature.
+    ///
+    /// # Arguments
+    /// * `ctx` - context instance
+    /// * `to` - contract address to call
+    /// * `signature` - function signature with output types e.g. `balanceOf(address)(uint256)`.
+    ///   Without output types, return data is not decoded, and empty vector is returned.
+    /// * `args` - arguments of the call
+    /// * `block` - block parameter, note that API might only support `Latest`
+    pub fn call_function(&self, ctx: &Context, to: &str, signature: &str, args: &[EvmAbiValue], block: EvmBlockParameter) -> Result<Vec<EvmAbiValue>, EvmError> {
+        let (_, output_types) = encode::parse_call_signature(signature)?;
+        let data = encode::encode_function_call(signature, args)?;
+        let res = self.call(ctx, to, &data, block)?;
+        match output_types {
+            Some(types) => decode::decode(&types, &res),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Call a function of contract according to its ABI e.g. as returned from
+    /// `Contracts::get_abi_typed()`, then decode its return data into named
+    /// values.
+    ///
+    /// # Arguments
+    /// * `ctx` - context instance
+    /// * `to` - contract address to call
+    /// * `abi` - contract ABI
+    /// * `name` - function name
+    /// * `args` - arguments of the call
+    /// * `block` - block parameter, note that API might only support `Latest`
+    pub fn call_function_with_abi(&self, ctx: &Context, to: &str, abi: &[EvmContractABIItem], name: &str, args: &[EvmAbiValue], block: EvmBlockParameter) -> Result<Vec<EvmAbiNamedValue>, EvmError> {
+        let (data, item) = encode::encode_function_call_from_abi(abi, name, args)?;
+        let res = self.call(ctx, to, &data, block)?;
+        decode::decode_function_output(item, &res)
+    }
+
+    /// Get the current gas price in wei.
+    ///
+    /// # Arguments
+    /// * `ctx` - context instance
+    pub fn get_gas_price(&self, ctx: &Context) -> Result<U256, EvmError> {
+        let res = self.request::<String>(ctx, "eth_gasPrice", "")?;
+        parse_hex_quantity_U256(&res).map_err(|e| EvmError::ErrorJsonParsing(Some(e)))
+    }
+
+    /// Estimate gas needed to execute a transaction without adding it to the
+    /// blockchain. Multiply it with gas price to preview transaction cost.
+    ///
+    /// # Arguments
+    /// * `ctx` - context instance
+    /// * `to` - recipient address
+    /// * `data` - call data, empty for plain transfer
+    /// * `value` - optional value to send in wei
+    /// * `gas` - optional gas limit
+    /// * `gas_price` - optional gas price in wei
+    pub fn estimate_gas(&self, ctx: &Context, to: &str, data: &[u8], value: Option<U256>, gas: Option<U256>, gas_price: Option<U256>) -> Result<U256, EvmError> {
+        let res = self.request::<String>(ctx, "eth_estimateGas", &Proxy::estimate_gas_params(to, data, value, gas, gas_price))?;
+        parse_hex_quantity_U256(&res).map_err(|e| EvmError::ErrorJsonParsing(Some(e)))
+    }
+
+    /// Broadcast a signed transaction. Return its transaction hash.
+    ///
+    /// Transaction is sent via POST as it can be too large to fit in URL.
+    /// Rejection from node e.g. nonce too low, or insufficient funds is
+    /// returned as `ErrorTransactionRejected` with its reason categorized.
+    ///
+    /// # Arguments
+    /// * `ctx` - context instance
+    /// * `signed_tx` - signed transaction in hex, prefixed with `0x` or not
+    pub fn send_raw_transaction(&self, ctx: &Context, signed_tx: &str) -> Result<String, EvmError> {
+        let hex = match decode::decode_hex(signed_tx) {
+            Ok(tx) if !tx.is_empty() => encode::encode_hex(&tx),
+            _ => return Err(EvmError::ErrorParameter(Some("signed transaction must be non-empty hex string".to_owned()))),
+        };
+
+        let json = http::post_form::<EvmProxyResponse>(ctx, &[
+            ("module", "proxy"),
+            ("action", "eth_sendRawTransaction"),
+            ("hex", &hex),
+        ])?;
+        Proxy::transaction_hash_from_response(json)
+    }
+
+    /// Internal function supporting to make JSON-RPC call via API then get its
+    /// result as `T`.
+    ///
+    /// # Arguments
+    /// * `ctx` - context instance
+    /// * `action` - JSON-RPC method e.g. `eth_blockNumber`
+    /// * `params` - query parameters of the call, each one prefixed with `&`
+    fn request<T>(&self, ctx: &Context, action: &str, params: &str) -> Result<T, EvmError>
+    where
+        T: serde::de::DeserializeOwned
+    {
+        let json = http::get_json::<EvmProxyResponse>(ctx, &format!("module=proxy&action={}{}", action, params))?;
+        Proxy::result_from_response::<T>(json)
+    }
+
+    /// Internal function supporting to create query parameters of
+    /// `eth_estimateGas`. Optional ones are omitted if not set.
+    pub(crate) fn estimate_gas_params(to: &str, data: &[u8], value: Option<U256>, gas: Option<U256>, gas_price: Option<U256>) -> String {
+        let mut params = format!("&to={}", to);
+        if !data.is_empty() {
+            params.push_str(&format!("&data={}", encode::encode_hex(data)));
+        }
+        if let Some(value) = value {
+            params.push_str(&format!("&value={:#x}", value));
+        }
+        if let Some(gas) = gas {
+            params.push_str(&format!("&gas={:#x}", gas));
+        }
+        if let Some(gas_price) = gas_price {
+            params.push_str(&format!("&gasPrice={:#x}", gas_price));
+        }
+        params
+    }
+
+    /// Internal function supporting to check whether code belongs to contract.
+    /// Empty code, and EIP-7702 delegation designator belong to EOA.
+    ///
+    /// # Arguments
+    /// * `code` - code deployed at an address
+    pub(crate) fn is_contract_code(code: &[u8]) -> bool {
+        let is_delegation = code.len() == EIP7702_DELEGATION_PREFIX.len() + 20 && code.starts_with(&EIP7702_DELEGATION_PREFIX);
+        !code.is_empty() && !is_delegation
+    }
+
+    /// Internal function supporting to parse storage value in hex into 32-byte
+    /// word. Value with leading zeros trimmed is left-padded.
+    ///
+    /// # Arguments
+    /// * `text` - storage value in hex
+    pub(crate) fn storage_value_from_hex(text: &str) -> Result<H256, EvmError> {
+        let value = decode::decode_hex(text)?;
+        if value.len() > 32 {
+            return Err(EvmError::ErrorJsonParsing(Some(format!("storage value '{}' is longer than 32 bytes", text))));
+        }
+
+        let mut word = [0u8; 32];
+        word[32 - value.len()..].copy_from_slice(&value);
+        Ok(H256::from(word))
+    }
+
+    /// Internal function supporting to get transaction hash from response of
+    /// broadcasting transaction. JSON-RPC error is categorized into
+    /// `ErrorTransactionRejected`.
+    ///
+    /// # Arguments
+    /// * `json` - API response
+    pub(crate) fn transaction_hash_from_response(json: EvmProxyResponse) -> Result<String, EvmError> {
+        if let Some(error) = json.error {
+            return Err(EvmError::ErrorTransactionRejected(TransactionRejection::from_message(&error.message), error.message));
+        }
+        Proxy::result_from_response::<String>(json)
+    }
+
+    /// Internal function supporting to get result of JSON-RPC call as `T`
+    /// from API response. Missing, or `null` result is deserialized as JSON's
+    /// `null` so `T` should be `Option` if such case is expected.
+    ///
+    /// # Arguments
+    /// * `json` - API response
+    pub(crate) fn result_from_response<T>(json: EvmProxyResponse) -> Result<T, EvmError>
+    where
+        T: serde::de::DeserializeOwned
+    {
+        if let Some(error) = json.error {
+            return Err(EvmError::ErrorApiResponse(format!("code:{}, message:{}", error.code, error.message)));
+        }
+
+        // API's own error e.g. invalid API key, or rate limit
+        if json.status.as_deref() == Some("0") {
+            let message = json.message.unwrap_or_default();
+            return match json.result {
+                Some(serde_json::Value::String(txt)) => Err(EvmError::ErrorApiResponse(format!("message:{}, result:{}", message, txt))),
+                _ => Err(EvmError::ErrorApiResponse(format!("message:{}", message))),
+            };
+        }
+
+        serde_json::from_value::<T>(json.result.unwrap_or(serde_json::Value::Null))
+            .map_err(|e| EvmError::ErrorJsonParsing(Some(format!("result of JSON-RPC call; err={}", e))))
+    }
+}
@@ -0,0 +1,101 @@
+use crate::prelude::*;
+use crate::types::evm_types::*;
+use crate::environ::Context;
+use crate::api::http;
+
+/// Transactions namespace containing related APIs about transaction status
+pub struct Transactions;
+
+impl Transactions {
+    /// Get execution status of a transaction i.e. whether it has error, and
+    /// its description.
+    ///
+    /// # Arguments
+    /// * `ctx` - context instance
+    /// * `tx_hash` - transaction hash
+    pub fn get_status(&self, ctx: &Context, tx_hash: &str) -> Result<EvmTransactionExecutionStatus, EvmError> {
+        let json = self.request::<EvmTransactionExecutionStatusResponse>(ctx, "getstatus", tx_hash)?;
+        Transactions::execution_status_from_response(json)
+    }
+
+    /// Get receipt status of a transaction. Return `None` if it is unknown
+    /// i.e. transaction before Byzantium hard fork.
+    ///
+    /// # Arguments
+    /// * `ctx` - context instance
+    /// * `tx_hash` - transaction hash
+    pub fn get_receipt_status(&self, ctx: &Context, tx_hash: &str) -> Result<Option<bool>, EvmError> {
+        let json = self.request::<EvmTransactionReceiptStatusResponse>(ctx, "gettxreceiptstatus", tx_hash)?;
+        Transactions::receipt_status_from_response(json)
+    }
+
+    /// Get both execution, and receipt status of multiple transactions. Each
+    /// transaction needs 2 requests, and they are spaced out so that no more
+    /// than `requests_per_second` requests are made in a second e.g. 5 for
+    /// free API key.
+    ///
+    /// Returned vector has one item per transaction in the same order as
+    /// `tx_hashes`. Error of one transaction e.g. rate limit doesn't stop the
+    /// rest of them.
+    ///
+    /// # Arguments
+    /// * `ctx` - context instance
+    /// * `tx_hashes` - transaction hashes
+    /// * `requests_per_second` - rate limit of API key
+    pub fn get_statuses(&self, ctx: &Context, tx_hashes: &[&str], requests_per_second: u32) -> Result<Vec<Result<EvmTransactionStatus, EvmError>>, EvmError> {
+        let mut limiter = http::RateLimiter::create(requests_per_second)?;
+
+        let mut res = Vec::with_capacity(tx_hashes.len());
+        for tx_hash in tx_hashes.iter() {
+            limiter.wait();
+            let execution = self.get_status(ctx, tx_hash);
+            limiter.wait();
+            let receipt_status = self.get_receipt_status(ctx, tx_hash);
+
+            res.push(execution.and_then(|execution| Ok(EvmTransactionStatus {
+                tx_hash: tx_hash.to_string(),
+                execution,
+                receipt_status: receipt_status?,
+            })));
+        }
+        Ok(res)
+    }
+
+    /// Internal function supporting to make request of transaction status by
+    /// its hash, then parse response as `T`.
+    ///
+    /// # Arguments
+    /// * `ctx` - context instance
+    /// * `action` - either `getstatus`, or `gettxreceiptstatus`
+    /// * `tx_hash` - transaction hash
+    fn request<T>(&self, ctx: &Context, action: &str, tx_hash: &str) -> Result<T, EvmError>
+    where
+        T: serde::de::DeserializeOwned
+    {
+        http::get_json(ctx, &format!("module=transaction&action={}&txhash={}", action, tx_hash))
+    }
+
+    /// Internal function supporting to get execution status from API response.
+    ///
+    /// # Arguments
+    /// * `json` - API response
+    pub(crate) fn execution_status_from_response(json: EvmTransactionExecutionStatusResponse) -> Result<EvmTransactionExecutionStatus, EvmError> {
+        match json.result {
+            EvmTransactionExecutionStatusResult::Success(status) if json.status == "1" => Ok(status),
+            EvmTransactionExecutionStatusResult::Failed(txt) => Err(EvmError::ErrorApiResponse(format!("message:{}, result:{}", json.message, txt))),
+            _ => Err(EvmError::ErrorApiResponse(format!("message:{}", json.message))),
+        }
+    }
+
+    /// Internal function supporting to get receipt status from API response.
+    ///
+    /// # Arguments
+    /// * `json` - API response
+    pub(crate) fn receipt_status_from_response(json: EvmTransactionReceiptStatusResponse) -> Result<Option<bool>, EvmError> {
+        match json.result {
+            EvmTransactionReceiptStatusResult::Success(status) if json.status == "1" => Ok(status.is_success()),
+            EvmTransactionReceiptStatusResult::Failed(txt) => Err(EvmError::ErrorApiResponse(format!("message:{}, result:{}", json.message, txt))),
+            _ => Err(EvmError::ErrorApiResponse(format!("message:{}", json.message))),
+        }
+    }
+}
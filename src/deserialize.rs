@@ -1,6 +1,7 @@
 use crate::prelude::*;
+use crate::types::evm_types::{EvmContractABIItem, EvmContractSourceFile};
 
-use serde::{Deserialize, Deserializer};
+use serde::{Deserialize, Deserializer, Serializer};
 
 /// Deserializing function from `String` to `bool`.
 pub fn de_string_to_bool<'de, D>(deserializer: D) -> Result<bool, D::Error>
@@ -41,13 +42,133 @@ where
     U256::from_dec_str(&buf).map_err(serde::de::Error::custom)
 }
 
+/// Parse hexadecimal quantity string prefixed with `0x` into `U256`.
+/// `0x` alone is treated as zero.
+#[allow(non_snake_case)]
+pub fn parse_hex_quantity_U256(text: &str) -> Result<U256, String> {
+    let digits = match text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        Some(digits) => digits,
+        None => return Err(format!("hex quantity '{}' is not prefixed with 0x", text)),
+    };
+    if digits.is_empty() {
+        return Ok(U256::zero());
+    }
+    if digits.len() > 64 {
+        return Err(format!("hex quantity '{}' is out of range", text));
+    }
+    U256::from_str_radix(digits, 16).map_err(|e| format!("invalid hex quantity '{}'; err={:?}", text, e))
+}
+
+/// Parse hexadecimal quantity string prefixed with `0x` into `u64`.
+/// `0x` alone is treated as zero.
+pub fn parse_hex_quantity_u64(text: &str) -> Result<u64, String> {
+    let value = parse_hex_quantity_U256(text)?;
+    if value > U256::from(u64::MAX) {
+        return Err(format!("hex quantity '{}' is out of range", text));
+    }
+    Ok(value.as_u64())
+}
+
+/// Parse `Library` field of contract's metadata into map of library name to
+/// its address. Entries are separated by `;`, or `,` and each one is in form
+/// of `<name>:<address>` in which address might not be prefixed with `0x`.
+///
+/// # Arguments
+/// * `library` - `Library` field as returned from API
+pub fn parse_libraries(library: &str) -> Result<std::collections::BTreeMap<String, String>, EvmError> {
+    let mut res = std::collections::BTreeMap::new();
+    for entry in library.split([';', ',']).map(|e| e.trim()).filter(|e| !e.is_empty()) {
+        let (name, address) = match entry.split_once(':') {
+            Some((name, address)) => (name.trim(), address.trim()),
+            None => return Err(EvmError::ErrorParameter(Some(format!("invalid library entry '{}'", entry)))),
+        };
+        let address = address.strip_prefix("0x").unwrap_or(address);
+        res.insert(name.to_owned(), format!("0x{}", address));
+    }
+    Ok(res)
+}
+
+/// Parse contract ABI in JSON text into typed structure.
+///
+/// # Arguments
+/// * `abi_text` - contract ABI in JSON text
+pub fn parse_abi(abi_text: &str) -> Result<Vec<EvmContractABIItem>, EvmError> {
+    match serde_json::from_str::<Vec<EvmContractABIItem>>(abi_text) {
+        Ok(json_obj) => Ok(json_obj),
+        Err(e) => Err(EvmError::ErrorInternalGeneric(Some(format!("create JSON object from string; err={}", e)))),
+    }
+}
+
+/// Deserializing function from hexadecimal quantity `String` e.g. `0x1b4` to `u64`.
+pub fn de_hex_string_to_u64<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: Deserializer<'de>
+{
+    let buf = String::deserialize(deserializer)?;
+    parse_hex_quantity_u64(&buf).map_err(serde::de::Error::custom)
+}
+
+/// Deserializing function from hexadecimal quantity `String` e.g. `0x1b4` to
+/// `primitive_types::U256`.
+#[allow(non_snake_case)]
+pub fn de_hex_string_to_U256<'de, D>(deserializer: D) -> Result<U256, D::Error>
+where
+    D: Deserializer<'de>
+{
+    let buf = String::deserialize(deserializer)?;
+    parse_hex_quantity_U256(&buf).map_err(serde::de::Error::custom)
+}
+
+/// Deserializing function from optional hexadecimal quantity `String` to
+/// `Option<u64>`. Missing field has to be marked with `#[serde(default)]`.
+pub fn de_opt_hex_string_to_u64<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
+where
+    D: Deserializer<'de>
+{
+    match Option::<String>::deserialize(deserializer)? {
+        Some(buf) => parse_hex_quantity_u64(&buf).map(Some).map_err(serde::de::Error::custom),
+        None => Ok(None),
+    }
+}
+
+/// Deserializing function from optional hexadecimal quantity `String` to
+/// `Option<primitive_types::U256>`. Missing field has to be marked with
+/// `#[serde(default)]`.
+#[allow(non_snake_case)]
+pub fn de_opt_hex_string_to_U256<'de, D>(deserializer: D) -> Result<Option<U256>, D::Error>
+where
+    D: Deserializer<'de>
+{
+    match Option::<String>::deserialize(deserializer)? {
+        Some(buf) => parse_hex_quantity_U256(&buf).map(Some).map_err(serde::de::Error::custom),
+        None => Ok(None),
+    }
+}
+
+/// Deserializing function from comma-separated `String` of decimal numbers
+/// e.g. `"0.37,0.89"` to `Vec<f64>`. Empty string is deserialized as empty
+/// vector.
+pub fn de_comma_separated_string_to_vec_f64<'de, D>(deserializer: D) -> Result<Vec<f64>, D::Error>
+where
+    D: Deserializer<'de>
+{
+    let buf = String::deserialize(deserializer)?;
+    buf.split(',')
+        .map(|item| item.trim())
+        .filter(|item| !item.is_empty())
+        .map(|item| item.parse::<f64>().map_err(serde::de::Error::custom))
+        .collect()
+}
+
 /// Deserializing function specifically for constructor's arguments from 'String'
 /// to `Vec<String>`.
 /// 
 /// Input format must be 64 characters in length of hexadecimal string without `0x` prefixed
 /// for each of item; concatenated together as a long string.
 ///
-/// Each string item as returned doesn't prefix with `0x`.
+/// Each string item as returned doesn't prefix with `0x`. Trailing characters
+/// which don't make up a whole item are kept as the last item so that
+/// malformed input can be detected when decoding.
 pub fn de_constructor_arguments_string_to_vec_string<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
 where
     D: Deserializer<'de>
@@ -59,17 +180,97 @@ where
     }
 
     // read each time 64 characters for one item of argument
-    if str_buf.len() < 64 {
-        return Err("Input string length cannot be less than 64 characters").map_err(serde::de::Error::custom);
-    }
-
     let mut offset_i: usize = 0;
     let mut res_vec: Vec<String> = Vec::new();
 
-    while offset_i + 64 <= str_buf.len() {
-        res_vec.push( (&str_buf[offset_i..offset_i+64]).to_owned() );
-        offset_i = offset_i + 64;
+    while offset_i < str_buf.len() {
+        let end = std::cmp::min(offset_i + 64, str_buf.len());
+        match str_buf.get(offset_i..end) {
+            Some(item) => res_vec.push(item.to_owned()),
+            None => return Err(serde::de::Error::custom("Input string is not in hexadecimal format")),
+        }
+        offset_i = end;
     }
 
     Ok(res_vec)
 }
+
+/// Deserializing function for `sources` field of Solidity's Standard JSON input
+/// from map of path to object with `content` field into
+/// `Vec<EvmContractSourceFile>`.
+///
+/// Order of files is kept as it appears in the input.
+pub fn de_sources_map_to_vec_source_file<'de, D>(deserializer: D) -> Result<Vec<EvmContractSourceFile>, D::Error>
+where
+    D: Deserializer<'de>
+{
+    /// Each entry of `sources` field. `content` can be missing if such file is
+    /// specified via `urls` instead. Vyper's JSON interface is specified via
+    /// `abi` instead in which case it is kept as content in JSON text.
+    #[derive(serde::Deserialize)]
+    struct SourceEntry {
+        #[serde(default)]
+        content: Option<String>,
+
+        #[serde(default)]
+        abi: Option<serde_json::Value>,
+    }
+
+    struct SourcesVisitor;
+
+    impl<'de> serde::de::Visitor<'de> for SourcesVisitor {
+        type Value = Vec<EvmContractSourceFile>;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            formatter.write_str("a map of source path to its content")
+        }
+
+        fn visit_map<M>(self, mut map: M) -> Result<Self::Value, M::Error>
+        where
+            M: serde::de::MapAccess<'de>
+        {
+            let mut res = Vec::with_capacity(map.size_hint().unwrap_or(0));
+            while let Some((path, entry)) = map.next_entry::<String, SourceEntry>()? {
+                let content = match (entry.content, entry.abi) {
+                    (Some(content), _) => content,
+                    (None, Some(abi)) => abi.to_string(),
+                    (None, None) => String::new(),
+                };
+                res.push(EvmContractSourceFile { path, content });
+            }
+            Ok(res)
+        }
+    }
+
+    deserializer.deserialize_map(SourcesVisitor)
+}
+
+/// Serializing function as a counterpart of `de_sources_map_to_vec_source_file`
+/// from `Vec<EvmContractSourceFile>` into map of path to object with `content`
+/// field.
+///
+/// Order of files is kept as it appears in the vector. Vyper's JSON interface
+/// i.e. file with `.json` extension is written back as `abi` field.
+pub fn ser_vec_source_file_to_sources_map<S>(sources: &[EvmContractSourceFile], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer
+{
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "lowercase")]
+    enum SourceEntry<'a> {
+        Content(&'a str),
+        Abi(serde_json::Value),
+    }
+
+    serializer.collect_map(sources.iter().map(|file| {
+        let abi = match file.path.ends_with(".json") {
+            true => serde_json::from_str::<serde_json::Value>(&file.content).ok(),
+            false => None,
+        };
+        let entry = match abi {
+            Some(abi) => SourceEntry::Abi(abi),
+            None => SourceEntry::Content(&file.content),
+        };
+        (&file.path, entry)
+    }))
+}
@@ -0,0 +1,248 @@
+use crate::prelude::*;
+use crate::types::evm_types::*;
+use crate::types::abi_types::EvmAbiParamType;
+use crate::api::contracts::Contracts;
+use crate::environ::Context;
+use crate::evmscan;
+use crate::export;
+
+use std::collections::BTreeMap;
+
+/// Number of unchanged lines shown around each change in unified diff
+static CONTEXT_RADIUS: usize = 3;
+
+/// Get verified source code of two contracts, then compare them. Contracts
+/// can be on different chains as each one has its own context.
+///
+/// # Arguments
+/// * `old_ctx` - context of old contract
+/// * `old_address` - old contract address
+/// * `new_ctx` - context of new contract
+/// * `new_address` - new contract address
+pub fn diff_contracts(old_ctx: &Context, old_address: &str, new_ctx: &Context, new_address: &str) -> Result<EvmContractSourceDiff, EvmError> {
+    let contracts = evmscan::contracts();
+    let old = contracts.get_verified_source_code_typed(old_ctx, old_address)?;
+    let new = contracts.get_verified_source_code_typed(new_ctx, new_address)?;
+    diff_verified_sources(&old, &new)
+}
+
+/// Compare verified source code of two contracts including their source files,
+/// compiler settings, and ABI.
+///
+/// # Arguments
+/// * `old` - old verified source code
+/// * `new` - new verified source code
+pub fn diff_verified_sources(old: &EvmContractVerifiedSource, new: &EvmContractVerifiedSource) -> Result<EvmContractSourceDiff, EvmError> {
+    let old_abi = Contracts::parse_abi(&old.contract.abi)?;
+    let new_abi = Contracts::parse_abi(&new.contract.abi)?;
+
+    let mut old_files = old.sources.clone();
+    old_files.extend(old.interfaces.iter().cloned());
+    let mut new_files = new.sources.clone();
+    new_files.extend(new.interfaces.iter().cloned());
+
+    Ok(EvmContractSourceDiff {
+        files: diff_source_files(&old_files, &new_files),
+        settings: diff_settings(old, new)?,
+        abi: diff_abi(&old_abi, &new_abi),
+    })
+}
+
+/// Compare source files aligned by their path. Unchanged files are not
+/// included in the result.
+///
+/// As single-file source code is named after its contract, if both sides have
+/// exactly one file, they are compared against each other regardless of path.
+///
+/// # Arguments
+/// * `old` - old source files
+/// * `new` - new source files
+pub fn diff_source_files(old: &[EvmContractSourceFile], new: &[EvmContractSourceFile]) -> Vec<EvmSourceFileDiff> {
+    if old.len() == 1 && new.len() == 1 {
+        return diff_file(Some(&old[0]), Some(&new[0])).into_iter().collect();
+    }
+
+    let mut res = Vec::new();
+    for old_file in old.iter() {
+        let new_file = new.iter().find(|f| f.path == old_file.path);
+        res.extend(diff_file(Some(old_file), new_file));
+    }
+    for new_file in new.iter().filter(|f| !old.iter().any(|o| o.path == f.path)) {
+        res.extend(diff_file(None, Some(new_file)));
+    }
+    res
+}
+
+/// Compare compiler settings i.e. compiler version, language, license, and
+/// settings of Standard JSON input which is synthesized from contract's
+/// metadata for single-file source code. `outputSelection` is ignored as it
+/// doesn't affect compilation result.
+///
+/// # Arguments
+/// * `old` - old verified source code
+/// * `new` - new verified source code
+pub fn diff_settings(old: &EvmContractVerifiedSource, new: &EvmContractVerifiedSource) -> Result<Vec<EvmSettingDiff>, EvmError> {
+    let old_settings = flatten_settings(old)?;
+    let new_settings = flatten_settings(new)?;
+
+    let mut names: Vec<&String> = old_settings.keys().chain(new_settings.keys()).collect();
+    names.sort();
+    names.dedup();
+
+    Ok(names.into_iter()
+        .filter(|name| old_settings.get(*name) != new_settings.get(*name))
+        .map(|name| EvmSettingDiff {
+            name: name.clone(),
+            old: old_settings.get(name).cloned(),
+            new: new_settings.get(name).cloned(),
+        })
+        .collect())
+}
+
+/// Compare ABI items. Function, event, and error are matched by their
+/// signature, thus changing their inputs shows up as one removed, and one
+/// added item. Constructor, receive, and fallback are matched by their type.
+///
+/// # Arguments
+/// * `old` - old contract ABI
+/// * `new` - new contract ABI
+pub fn diff_abi(old: &[EvmContractABIItem], new: &[EvmContractABIItem]) -> Vec<EvmAbiItemChange> {
+    let old_items = index_abi(old);
+    let new_items = index_abi(new);
+
+    let mut res = Vec::new();
+    for (key, old_item) in old_items.iter() {
+        let (item_type, signature) = key.clone();
+        let new_item = match new_items.iter().find(|(k, _)| k == key) {
+            Some((_, item)) => item,
+            None => {
+                res.push(EvmAbiItemChange { item_type, signature, kind: EvmAbiChangeKind::Removed });
+                continue;
+            },
+        };
+
+        let old_mutability = state_mutability(old_item);
+        let new_mutability = state_mutability(new_item);
+        if old_mutability != new_mutability {
+            res.push(EvmAbiItemChange {
+                item_type,
+                signature: signature.clone(),
+                kind: EvmAbiChangeKind::MutabilityChanged(old_mutability, new_mutability),
+            });
+        }
+        if canonical_types(&old_item.outputs) != canonical_types(&new_item.outputs) {
+            res.push(EvmAbiItemChange { item_type, signature: signature.clone(), kind: EvmAbiChangeKind::OutputsChanged });
+        }
+        if item_type == EvmContractABIItemKind::Constructor && canonical_types(&old_item.inputs) != canonical_types(&new_item.inputs) {
+            res.push(EvmAbiItemChange { item_type, signature: signature.clone(), kind: EvmAbiChangeKind::InputsChanged });
+        }
+    }
+    for (key, _) in new_items.iter().filter(|(k, _)| !old_items.iter().any(|(o, _)| o == k)) {
+        let (item_type, signature) = key.clone();
+        res.push(EvmAbiItemChange { item_type, signature, kind: EvmAbiChangeKind::Added });
+    }
+    res
+}
+
+/// State mutability of ABI item. Older compilers only emit legacy `constant`,
+/// and `payable` fields, so it's derived from them if `stateMutability` is
+/// absent.
+fn state_mutability(item: &EvmContractABIItem) -> Option<EvmContractStateMutability> {
+    if item.state_mutability.is_some() {
+        return item.state_mutability;
+    }
+    match (item.constant, item.payable) {
+        (None, None) => None,
+        (Some(true), _) => Some(EvmContractStateMutability::View),
+        (_, Some(true)) => Some(EvmContractStateMutability::Payable),
+        _ => Some(EvmContractStateMutability::NonPayable),
+    }
+}
+
+/// Compare a pair of files. Return `None` if they are the same.
+fn diff_file(old: Option<&EvmContractSourceFile>, new: Option<&EvmContractSourceFile>) -> Option<EvmSourceFileDiff> {
+    let kind = match (old, new) {
+        (Some(o), Some(n)) if o.content == n.content => return None,
+        (Some(_), Some(_)) => EvmSourceFileChangeKind::Modified,
+        (Some(_), None) => EvmSourceFileChangeKind::Removed,
+        (None, Some(_)) => EvmSourceFileChangeKind::Added,
+        (None, None) => return None,
+    };
+
+    let old_content = old.map(|f| f.content.as_str()).unwrap_or("");
+    let new_content = new.map(|f| f.content.as_str()).unwrap_or("");
+    let old_header = old.map(|f| format!("a/{}", f.path)).unwrap_or_else(|| "/dev/null".to_owned());
+    let new_header = new.map(|f| format!("b/{}", f.path)).unwrap_or_else(|| "/dev/null".to_owned());
+
+    let unified_diff = similar::TextDiff::from_lines(old_content, new_content)
+        .unified_diff()
+        .context_radius(CONTEXT_RADIUS)
+        .header(&old_header, &new_header)
+        .to_string();
+
+    Some(EvmSourceFileDiff {
+        old_path: old.map(|f| f.path.clone()),
+        new_path: new.map(|f| f.path.clone()),
+        kind,
+        unified_diff,
+    })
+}
+
+/// Flatten compiler settings into map of dotted name to value in JSON text.
+fn flatten_settings(verified: &EvmContractVerifiedSource) -> Result<BTreeMap<String, String>, EvmError> {
+    let mut res = BTreeMap::new();
+    res.insert("compilerVersion".to_owned(), verified.contract.compiler_version.clone());
+    res.insert("language".to_owned(), verified.language.as_str().to_owned());
+    res.insert("licenseType".to_owned(), verified.contract.license_type.clone());
+
+    let settings = export::to_standard_json_input(verified)?.settings;
+    let value = match serde_json::to_value(settings) {
+        Ok(value) => value,
+        Err(e) => return Err(EvmError::ErrorInternalGeneric(Some(format!("Error serializing compiler settings; err={}", e)))),
+    };
+    flatten_json("", &value, &mut res);
+    res.remove("outputSelection");
+    res.retain(|name, _| !name.starts_with("outputSelection."));
+    Ok(res)
+}
+
+/// Flatten nested JSON objects into dotted names. Other values are kept as
+/// JSON text.
+fn flatten_json(prefix: &str, value: &serde_json::Value, res: &mut BTreeMap<String, String>) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map.iter() {
+                let name = if prefix.is_empty() { key.clone() } else { format!("{}.{}", prefix, key) };
+                flatten_json(&name, value, res);
+            }
+        },
+        serde_json::Value::Null => (),
+        serde_json::Value::String(text) => { res.insert(prefix.to_owned(), text.clone()); },
+        _ => { res.insert(prefix.to_owned(), value.to_string()); },
+    }
+}
+
+/// Index ABI items by their type, and signature keeping the first occurrence.
+fn index_abi(abi: &[EvmContractABIItem]) -> Vec<((EvmContractABIItemKind, String), &EvmContractABIItem)> {
+    let mut res: Vec<((EvmContractABIItemKind, String), &EvmContractABIItem)> = Vec::with_capacity(abi.len());
+    for item in abi.iter() {
+        let signature = match item.r#type {
+            EvmContractABIItemKind::Constructor | EvmContractABIItemKind::Receive | EvmContractABIItemKind::Fallback => String::new(),
+            _ => item.signature().unwrap_or_else(|_| item.name.clone().unwrap_or_default()),
+        };
+        let key = (item.r#type, signature);
+        if !res.iter().any(|(k, _)| *k == key) {
+            res.push((key, item));
+        }
+    }
+    res
+}
+
+/// Canonical types of inputs, or outputs. Parameter which cannot be resolved
+/// is kept as its type as-is.
+fn canonical_types(params: &Option<Vec<EvmContractABIItemType>>) -> Vec<String> {
+    params.iter()
+        .flatten()
+        .map(|p| EvmAbiParamType::from_abi_param(p).map(|t| t.canonical()).unwrap_or_else(|_| p.r#type.clone()))
+        .collect()
+}
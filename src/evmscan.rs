@@ -25,3 +25,38 @@ pub fn stats() -> stats::Stats {
 pub fn contracts() -> contracts::Contracts {
     contracts::Contracts{}
 }
+
+/// Get APIs in `Proxy` namespace.
+/// NOTE: Users should cache the returned `Proxy` as this function will newly
+/// create such instance every time called although it's cheap.
+pub fn proxy() -> proxy::Proxy {
+    proxy::Proxy{}
+}
+
+/// Get APIs in `Transactions` namespace.
+/// NOTE: Users should cache the returned `Transactions` as this function will newly
+/// create such instance every time called although it's cheap.
+pub fn transactions() -> transactions::Transactions {
+    transactions::Transactions{}
+}
+
+/// Get APIs in `Blocks` namespace.
+/// NOTE: Users should cache the returned `Blocks` as this function will newly
+/// create such instance every time called although it's cheap.
+pub fn blocks() -> blocks::Blocks {
+    blocks::Blocks{}
+}
+
+/// Get APIs in `Logs` namespace.
+/// NOTE: Users should cache the returned `Logs` as this function will newly
+/// create such instance every time called although it's cheap.
+pub fn logs() -> logs::Logs {
+    logs::Logs{}
+}
+
+/// Get APIs in `GasTracker` namespace.
+/// NOTE: Users should cache the returned `GasTracker` as this function will newly
+/// create such instance every time called although it's cheap.
+pub fn gastracker() -> gastracker::GasTracker {
+    gastracker::GasTracker{}
+}
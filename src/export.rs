@@ -0,0 +1,253 @@
+use crate::prelude::*;
+use crate::types::evm_types::*;
+
+use std::collections::BTreeMap;
+use std::path::{Component, Path, PathBuf};
+
+/// Directory inside Foundry project in which source files are written
+static FOUNDRY_SOURCE_DIR: &str = "src";
+
+/// File name of Standard JSON input written along with source files
+static STANDARD_JSON_INPUT_FILE: &str = "standard_json_input.json";
+
+/// Write source files, and interface files of verified contract into `dir`
+/// preserving their original paths as used in import statements. Return paths
+/// of written files.
+///
+/// # Arguments
+/// * `verified` - verified contract's source code
+/// * `dir` - directory to write source files into, it will be created if not exist
+pub fn export_source_files(verified: &EvmContractVerifiedSource, dir: &Path) -> Result<Vec<PathBuf>, EvmError> {
+    let mut res = Vec::with_capacity(verified.sources.len() + verified.interfaces.len());
+    for file in verified.sources.iter().chain(verified.interfaces.iter()) {
+        let path = dir.join(sanitize_source_path(&file.path)?);
+        write_file(&path, &file.content)?;
+        res.push(path);
+    }
+    Ok(res)
+}
+
+/// Write verified contract into `dir` as Foundry project. Source files are
+/// written under `src` directory preserving their original paths, along with
+/// `foundry.toml`, `remappings.txt`, and Standard JSON input which reproduces
+/// the verified compilation. Return paths of written files.
+///
+/// # Arguments
+/// * `verified` - verified contract's source code
+/// * `dir` - project directory, it will be created if not exist
+pub fn export_foundry_project(verified: &EvmContractVerifiedSource, dir: &Path) -> Result<Vec<PathBuf>, EvmError> {
+    let mut res = export_source_files(verified, &dir.join(FOUNDRY_SOURCE_DIR))?;
+
+    let path = dir.join("foundry.toml");
+    write_file(&path, &foundry_toml(verified)?)?;
+    res.push(path);
+
+    let mut remappings = foundry_remappings(verified)?.join("\n");
+    remappings.push('\n');
+    let path = dir.join("remappings.txt");
+    write_file(&path, &remappings)?;
+    res.push(path);
+
+    let input = to_standard_json_input(verified)?;
+    let text = match serde_json::to_string_pretty(&input) {
+        Ok(text) => text,
+        Err(e) => return Err(EvmError::ErrorInternalGeneric(Some(format!("Error serializing Standard JSON input; err={}", e)))),
+    };
+    let path = dir.join(STANDARD_JSON_INPUT_FILE);
+    write_file(&path, &text)?;
+    res.push(path);
+
+    Ok(res)
+}
+
+/// Create Standard JSON input which reproduces the verified compilation.
+///
+/// For source code submitted as Standard JSON input, its settings are kept
+/// as-is. Otherwise settings are synthesized from contract's metadata i.e.
+/// optimization, number of runs, EVM version, and libraries. Language, and
+/// Vyper's interface files are kept as well.
+/// In both cases, `outputSelection` is added if missing so that the compiler
+/// produces ABI, and bytecode.
+///
+/// # Arguments
+/// * `verified` - verified contract's source code
+pub fn to_standard_json_input(verified: &EvmContractVerifiedSource) -> Result<EvmContractStandardJsonInput, EvmError> {
+    let mut settings = match verified.settings {
+        Some(ref settings) => settings.clone(),
+        None => synthesize_settings(verified)?,
+    };
+
+    if !settings.other.contains_key("outputSelection") {
+        // Vyper selects output per file, but Solidity does per contract in file
+        let output_selection = match verified.language {
+            EvmContractLanguage::Vyper => serde_json::json!({
+                "*": ["abi", "evm.bytecode", "evm.deployedBytecode", "evm.methodIdentifiers"]
+            }),
+            _ => serde_json::json!({
+                "*": {
+                    "*": ["abi", "evm.bytecode", "evm.deployedBytecode", "evm.methodIdentifiers", "metadata"],
+                    "": ["ast"]
+                }
+            }),
+        };
+        settings.other.insert("outputSelection".to_owned(), output_selection);
+    }
+
+    Ok(EvmContractStandardJsonInput {
+        language: verified.language.as_str().to_owned(),
+        sources: verified.sources.clone(),
+        interfaces: verified.interfaces.clone(),
+        settings: Some(settings),
+    })
+}
+
+/// Create content of `foundry.toml` for the verified contract.
+///
+/// # Arguments
+/// * `verified` - verified contract's source code
+pub fn foundry_toml(verified: &EvmContractVerifiedSource) -> Result<String, EvmError> {
+    let settings = match verified.settings {
+        Some(ref settings) => settings.clone(),
+        None => synthesize_settings(verified)?,
+    };
+
+    let mut lines = vec![
+        "[profile.default]".to_owned(),
+        format!("src = {}", toml_string(FOUNDRY_SOURCE_DIR)),
+        format!("out = {}", toml_string("out")),
+        "libs = []".to_owned(),
+    ];
+
+    if let Ok(compiler) = verified.contract.parsed_compiler_version() {
+        if compiler.kind == EvmCompilerKind::Solc {
+            lines.push(format!("solc_version = {}", toml_string(&compiler.version.to_string())));
+        }
+    }
+    if let Some(ref optimizer) = settings.optimizer {
+        lines.push(format!("optimizer = {}", optimizer.enabled.unwrap_or(false)));
+        if let Some(runs) = optimizer.runs {
+            lines.push(format!("optimizer_runs = {}", runs));
+        }
+    }
+    if let Some(ref evm_version) = settings.evm_version {
+        lines.push(format!("evm_version = {}", toml_string(evm_version)));
+    }
+    if let Some(via_ir) = settings.via_ir {
+        lines.push(format!("via_ir = {}", via_ir));
+    }
+
+    let remappings: Vec<String> = foundry_remappings(verified)?.iter().map(|r| toml_string(r)).collect();
+    lines.push(format!("remappings = [{}]", remappings.join(", ")));
+
+    // libraries are in form of `<path>:<name>:<address>` with path relative to
+    // project's root
+    let mut libraries = Vec::new();
+    for (path, names) in settings.libraries.iter() {
+        let path = sanitize_source_path(path)?;
+        for (name, address) in names.iter() {
+            let library = format!("{}/{}:{}:{}", FOUNDRY_SOURCE_DIR, path.to_string_lossy(), name, address);
+            libraries.push(toml_string(&library));
+        }
+    }
+    if !libraries.is_empty() {
+        lines.push(format!("libraries = [{}]", libraries.join(", ")));
+    }
+
+    lines.push(String::new());
+    Ok(lines.join("\n"))
+}
+
+/// Create remappings which resolve original import paths into `src` directory
+/// of Foundry project. There is one remapping for each top-level directory, or
+/// file of source paths e.g. `@openzeppelin/=src/@openzeppelin/`.
+///
+/// Remappings as submitted with the source code are not used as source paths
+/// are already the result of applying them.
+///
+/// # Arguments
+/// * `verified` - verified contract's source code
+pub fn foundry_remappings(verified: &EvmContractVerifiedSource) -> Result<Vec<String>, EvmError> {
+    let mut prefixes = std::collections::BTreeSet::new();
+    for file in verified.sources.iter().chain(verified.interfaces.iter()) {
+        let path = sanitize_source_path(&file.path)?;
+        let path = path.to_string_lossy().replace('\\', "/");
+        match path.find('/') {
+            Some(index) => prefixes.insert(path[..index+1].to_owned()),
+            None => prefixes.insert(path),
+        };
+    }
+
+    Ok(prefixes.into_iter().map(|prefix| format!("{}={}/{}", prefix, FOUNDRY_SOURCE_DIR, prefix)).collect())
+}
+
+/// Synthesize compiler settings from contract's metadata for source code which
+/// was not submitted as Standard JSON input.
+fn synthesize_settings(verified: &EvmContractVerifiedSource) -> Result<EvmContractCompilerSettings, EvmError> {
+    let contract = &verified.contract;
+
+    let evm_version = contract.parsed_evm_version().as_solc_str().map(|v| v.to_owned());
+
+    // libraries of single-file source code can only be defined in that file
+    let mut libraries = BTreeMap::new();
+    let names = contract.parsed_libraries()?;
+    if !names.is_empty() {
+        if let Some(file) = verified.sources.first() {
+            libraries.insert(file.path.clone(), names);
+        }
+    }
+
+    // Vyper has no optimizer settings in the same form as Solidity
+    let optimizer = match verified.language {
+        EvmContractLanguage::Vyper => None,
+        _ => Some(EvmContractOptimizerSettings {
+            enabled: Some(contract.optimization_used),
+            runs: Some(contract.runs),
+            details: None,
+        }),
+    };
+
+    Ok(EvmContractCompilerSettings {
+        optimizer,
+        remappings: Vec::new(),
+        libraries,
+        evm_version,
+        via_ir: None,
+        other: serde_json::Map::new(),
+    })
+}
+
+/// Make source path safe to be joined under a directory. Leading `/`, and `.`
+/// components are removed, and `..` component is rejected.
+fn sanitize_source_path(path: &str) -> Result<PathBuf, EvmError> {
+    let mut res = PathBuf::new();
+    for component in Path::new(path).components() {
+        match component {
+            Component::Normal(c) => res.push(c),
+            Component::RootDir | Component::CurDir | Component::Prefix(_) => (),
+            Component::ParentDir => return Err(EvmError::ErrorParameter(Some(format!("source path '{}' points outside of its directory", path)))),
+        }
+    }
+    if res.as_os_str().is_empty() {
+        return Err(EvmError::ErrorParameter(Some(format!("invalid source path '{}'", path))));
+    }
+    Ok(res)
+}
+
+/// Quote text as TOML basic string.
+fn toml_string(text: &str) -> String {
+    // JSON string escaping is a valid subset of TOML basic string
+    serde_json::to_string(text).unwrap_or_else(|_| format!("\"{}\"", text))
+}
+
+/// Write content to file creating its parent directories if needed.
+fn write_file(path: &Path, content: &str) -> Result<(), EvmError> {
+    if let Some(parent) = path.parent() {
+        if let Err(e) = std::fs::create_dir_all(parent) {
+            return Err(EvmError::ErrorInternalGeneric(Some(format!("Error creating directory {}; err={}", parent.display(), e))));
+        }
+    }
+    if let Err(e) = std::fs::write(path, content) {
+        return Err(EvmError::ErrorInternalGeneric(Some(format!("Error writing file {}; err={}", path.display(), e))));
+    }
+    Ok(())
+}
@@ -30,8 +30,38 @@ impl std::fmt::Display for EvmError {
                     None => write!(f, "Invalid parameter"),
                 }
             },
+            EvmError::ErrorAbiCoding(ref msg) => write!(f, "Error in ABI encoding/decoding ({})", msg),
+            EvmError::ErrorProxyResolution(ref msg) => write!(f, "Error in resolving proxy's implementation ({})", msg),
+            EvmError::ErrorTransactionRejected(ref reason, ref msg) => write!(f, "Error transaction rejected by node ({:?}): {}", reason, msg),
         }
     }
 }
 
 impl std::error::Error for EvmError {}
+
+impl TransactionRejection {
+    /// Categorize error message as returned from node when broadcasting
+    /// transaction. Messages differ slightly across clients e.g. Geth's
+    /// `already known`, and older `known transaction: <hash>`.
+    ///
+    /// # Arguments
+    /// * `message` - error message from node
+    pub fn from_message(message: &str) -> Self {
+        let message = message.to_lowercase();
+        if message.contains("nonce too low") {
+            TransactionRejection::NonceTooLow
+        }
+        else if message.contains("insufficient funds") {
+            TransactionRejection::InsufficientFunds
+        }
+        else if message.contains("underpriced") {
+            TransactionRejection::Underpriced
+        }
+        else if message.contains("already known") || message.contains("known transaction") {
+            TransactionRejection::AlreadyKnown
+        }
+        else {
+            TransactionRejection::Other
+        }
+    }
+}
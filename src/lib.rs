@@ -12,6 +12,15 @@ pub mod evmscan;
 
 /// Abstraction-level module to hold various group of APIs
 pub mod api;
+
+/// Encoding and decoding of data according to contract ABI
+pub mod abi;
+
+/// Exporting verified contract's source code to file system
+pub mod export;
+
+/// Comparing verified source code of two contracts
+pub mod diff;
 mod impls;
 
 #[cfg(test)]
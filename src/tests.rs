@@ -11,3 +11,43 @@ pub mod ethereum_tests;
 /// Polygon tests
 #[cfg(test)]
 pub mod polygon_tests;
+
+/// Accounts tests which don't need network access
+#[cfg(test)]
+pub mod accounts_tests;
+
+/// Contracts tests which don't need network access
+#[cfg(test)]
+pub mod contracts_tests;
+
+/// ABI encoding, and decoding tests which don't need network access
+#[cfg(test)]
+pub mod abi_tests;
+
+/// Exporting source code tests which don't need network access
+#[cfg(test)]
+pub mod export_tests;
+
+/// Source diff tests which don't need network access
+#[cfg(test)]
+pub mod diff_tests;
+
+/// Proxy tests which don't need network access
+#[cfg(test)]
+pub mod proxy_tests;
+
+/// Transactions tests which don't need network access
+#[cfg(test)]
+pub mod transactions_tests;
+
+/// Blocks tests which don't need network access
+#[cfg(test)]
+pub mod blocks_tests;
+
+/// Logs tests which don't need network access
+#[cfg(test)]
+pub mod logs_tests;
+
+/// Gas Tracker tests which don't need network access
+#[cfg(test)]
+pub mod gastracker_tests;
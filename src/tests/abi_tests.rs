@@ -0,0 +1,353 @@
+/**
+ * Tests for encoding, and decoding of ABI data. No API key, or network access
+ * is needed.
+ *
+ * Most of encoded data are taken from examples of ABI specification
+ * https://docs.soliditylang.org/en/latest/abi-spec.html#examples
+ */
+#[cfg(test)]
+use crate::prelude::*;
+use crate::types::abi_types::*;
+use crate::api::contracts::Contracts;
+use crate::abi::signature::*;
+use crate::abi::decode::*;
+use crate::abi::encode::*;
+use crate::types::evm_types::EvmContractSourceCode;
+use crate::deserialize::de_constructor_arguments_string_to_vec_string;
+use crate::tests::contracts_tests::source_code_record;
+
+/// Encoding of `f(uint256,uint32[],bytes10,bytes)` with values
+/// `(0x123, [0x456, 0x789], "1234567890", "Hello, world!")`.
+static F_INPUT: &str = concat!(
+    "0x8be65246",
+    "0000000000000000000000000000000000000000000000000000000000000123",
+    "0000000000000000000000000000000000000000000000000000000000000080",
+    "3132333435363738393000000000000000000000000000000000000000000000",
+    "00000000000000000000000000000000000000000000000000000000000000e0",
+    "0000000000000000000000000000000000000000000000000000000000000002",
+    "0000000000000000000000000000000000000000000000000000000000000456",
+    "0000000000000000000000000000000000000000000000000000000000000789",
+    "000000000000000000000000000000000000000000000000000000000000000d",
+    "48656c6c6f2c20776f726c642100000000000000000000000000000000000000");
+
+/// Encoding of `g(uint256[][],string[])` with values
+/// `([[1, 2], [3]], ["one", "two", "three"])`.
+static G_INPUT: &str = concat!(
+    "0x2289b18c",
+    "0000000000000000000000000000000000000000000000000000000000000040",
+    "0000000000000000000000000000000000000000000000000000000000000140",
+    "0000000000000000000000000000000000000000000000000000000000000002",
+    "0000000000000000000000000000000000000000000000000000000000000040",
+    "00000000000000000000000000000000000000000000000000000000000000a0",
+    "0000000000000000000000000000000000000000000000000000000000000002",
+    "0000000000000000000000000000000000000000000000000000000000000001",
+    "0000000000000000000000000000000000000000000000000000000000000002",
+    "0000000000000000000000000000000000000000000000000000000000000001",
+    "0000000000000000000000000000000000000000000000000000000000000003",
+    "0000000000000000000000000000000000000000000000000000000000000003",
+    "0000000000000000000000000000000000000000000000000000000000000060",
+    "00000000000000000000000000000000000000000000000000000000000000a0",
+    "00000000000000000000000000000000000000000000000000000000000000e0",
+    "0000000000000000000000000000000000000000000000000000000000000003",
+    "6f6e650000000000000000000000000000000000000000000000000000000000",
+    "0000000000000000000000000000000000000000000000000000000000000003",
+    "74776f0000000000000000000000000000000000000000000000000000000000",
+    "0000000000000000000000000000000000000000000000000000000000000005",
+    "7468726565000000000000000000000000000000000000000000000000000000");
+
+static ABI: &str = r#"[
+    {"inputs":[{"name":"a","type":"uint256"},{"name":"b","type":"uint32[]"},{"name":"c","type":"bytes10"},{"name":"d","type":"bytes"}],"name":"f","outputs":[],"stateMutability":"nonpayable","type":"function"},
+    {"inputs":[{"name":"x","type":"uint256[][]"},{"name":"y","type":"string[]"}],"name":"g","outputs":[],"stateMutability":"nonpayable","type":"function"},
+    {"inputs":[{"name":"to","type":"address"},{"name":"amount","type":"uint256"}],"name":"transfer","outputs":[{"name":"","type":"bool"}],"stateMutability":"nonpayable","type":"function"}
+]"#;
+
+#[test]
+fn test_function_selector() {
+    assert_eq!(function_selector("transfer(address,uint256)"), [0xa9, 0x05, 0x9c, 0xbb]);
+    assert_eq!(function_selector("f(uint256,uint32[],bytes10,bytes)"), [0x8b, 0xe6, 0x52, 0x46]);
+    assert_eq!(format!("{:?}", keccak256(b"")), "0xc5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470");
+}
+
+#[test]
+fn test_parse_param_type() {
+    assert_eq!(EvmAbiParamType::parse("uint").unwrap(), EvmAbiParamType::Uint(256));
+    assert_eq!(EvmAbiParamType::parse("bytes32").unwrap(), EvmAbiParamType::FixedBytes(32));
+    assert_eq!(EvmAbiParamType::parse("(uint256,(address,bool)[])[2]").unwrap().canonical(), "(uint256,(address,bool)[])[2]");
+    assert_eq!(EvmAbiParamType::parse("tuple(int8,string)").unwrap().canonical(), "(int8,string)");
+    assert!(EvmAbiParamType::parse("uint7").is_err());
+    assert!(EvmAbiParamType::parse("bytes33").is_err());
+    assert!(EvmAbiParamType::parse("foo").is_err());
+
+    assert!(EvmAbiParamType::parse("(uint256,bytes)").unwrap().is_dynamic());
+    assert!(!EvmAbiParamType::parse("(uint256,bool[3])").unwrap().is_dynamic());
+    assert_eq!(EvmAbiParamType::parse("(uint256,bool[3])[2]").unwrap().head_size(), 256);
+}
+
+#[test]
+fn test_abi_item_signature_with_tuple() {
+    let abi = Contracts::parse_abi(r#"[{"inputs":[{"components":[{"name":"maker","type":"address"},{"name":"amounts","type":"uint256[2]"}],"name":"orders","type":"tuple[2][]"},{"name":"data","type":"bytes"}],"name":"fill","outputs":[],"stateMutability":"nonpayable","type":"function"}]"#).unwrap();
+    assert_eq!(abi[0].signature().unwrap(), "fill((address,uint256[2])[2][],bytes)");
+}
+
+#[test]
+fn test_i256() {
+    let minus_one = I256::from_i128(-1);
+    assert_eq!(minus_one.0, U256::MAX);
+    assert!(minus_one.is_negative());
+    assert_eq!(minus_one.abs(), U256::one());
+    assert_eq!(minus_one.to_i128(), Some(-1));
+    assert_eq!(format!("{}", I256::from_i128(-1234)), "-1234");
+    assert_eq!(I256::from_i128(i128::MIN).to_i128(), Some(i128::MIN));
+    assert_eq!(I256(U256::one() << 200).to_i128(), None);
+}
+
+#[test]
+fn test_decode_function_input_dynamic() {
+    let abi = Contracts::parse_abi(ABI).unwrap();
+
+    let call = decode_function_input(&abi, F_INPUT).unwrap().unwrap();
+    assert_eq!(call.name, "f");
+    assert_eq!(call.signature, "f(uint256,uint32[],bytes10,bytes)");
+    assert_eq!(call.selector, [0x8b, 0xe6, 0x52, 0x46]);
+    assert_eq!(call.inputs.len(), 4);
+    assert_eq!(call.inputs[0].name, "a");
+    assert_eq!(call.inputs[0].value, EvmAbiValue::Uint(U256::from(0x123)));
+    assert_eq!(call.inputs[1].param_type, EvmAbiParamType::Array(Box::new(EvmAbiParamType::Uint(32))));
+    assert_eq!(call.inputs[1].value, EvmAbiValue::Array(vec![EvmAbiValue::Uint(U256::from(0x456)), EvmAbiValue::Uint(U256::from(0x789))]));
+    assert_eq!(call.inputs[2].value, EvmAbiValue::FixedBytes(b"1234567890".to_vec()));
+    assert_eq!(call.inputs[3].value, EvmAbiValue::Bytes(b"Hello, world!".to_vec()));
+}
+
+#[test]
+fn test_decode_function_input_nested_dynamic() {
+    let abi = Contracts::parse_abi(ABI).unwrap();
+
+    let call = decode_function_input(&abi, G_INPUT).unwrap().unwrap();
+    assert_eq!(call.inputs[0].value, EvmAbiValue::Array(vec![
+        EvmAbiValue::Array(vec![EvmAbiValue::Uint(U256::from(1)), EvmAbiValue::Uint(U256::from(2))]),
+        EvmAbiValue::Array(vec![EvmAbiValue::Uint(U256::from(3))]),
+    ]));
+    assert_eq!(call.inputs[1].value, EvmAbiValue::Array(vec![
+        EvmAbiValue::String("one".to_owned()),
+        EvmAbiValue::String("two".to_owned()),
+        EvmAbiValue::String("three".to_owned()),
+    ]));
+}
+
+#[test]
+fn test_decode_function_input_no_match() {
+    let abi = Contracts::parse_abi(ABI).unwrap();
+
+    assert_eq!(decode_function_input(&abi, "0x").unwrap(), None);
+    assert_eq!(decode_function_input(&abi, "0x12345678").unwrap(), None);
+}
+
+#[test]
+fn test_decode_function_input_skips_unsupported_item() {
+    // an item with unsupported type before the matching one
+    let mut abi = Contracts::parse_abi(r#"[{"inputs":[{"name":"x","type":"uint7"}],"name":"h","outputs":[],"stateMutability":"nonpayable","type":"function"}]"#).unwrap();
+    abi.extend(Contracts::parse_abi(ABI).unwrap());
+
+    let call = decode_function_input(&abi, F_INPUT).unwrap().unwrap();
+    assert_eq!(call.name, "f");
+    assert_eq!(decode_function_input(&abi, "0x12345678").unwrap(), None);
+}
+
+#[test]
+fn test_decode_malformed() {
+    let abi = Contracts::parse_abi(ABI).unwrap();
+
+    // truncated data
+    assert!(decode_function_input(&abi, &F_INPUT[..F_INPUT.len() - 64]).is_err());
+    // dirty bits of address
+    let input = format!("0xa9059cbb{}{}", "ff00000000000000000000000000000000000000000000000000000000000001", "0000000000000000000000000000000000000000000000000000000000000001");
+    assert!(decode_function_input(&abi, &input).is_err());
+    // uint32 out of range
+    let data = decode_hex("0000000000000000000000000000000000000000000000000000000100000000").unwrap();
+    assert!(decode(&[EvmAbiParamType::Uint(32)], &data).is_err());
+    // sign-extended int8 is fine
+    let data = decode_hex("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff80").unwrap();
+    assert_eq!(decode(&[EvmAbiParamType::Int(8)], &data).unwrap(), vec![EvmAbiValue::Int(I256::from_i128(-128))]);
+}
+
+#[test]
+fn test_event_topic_and_canonical_signature() {
+    assert_eq!(format!("{:?}", event_topic("Transfer(address,address,uint256)")), "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef");
+    assert_eq!(canonical_signature(" balanceOf( address ) ").unwrap(), "balanceOf(address)");
+    assert_eq!(canonical_signature("foo(uint,(int,bytes)[])").unwrap(), "foo(uint256,(int256,bytes)[])");
+    assert!(canonical_signature("transfer(address to,uint256)").is_err());
+    assert!(canonical_signature("(uint256)").is_err());
+}
+
+#[test]
+fn test_signature_database() {
+    let mut db = EvmSignatureDatabase::create_with_defaults();
+
+    assert_eq!(db.lookup_function(&[0x09, 0x5e, 0xa7, 0xb3]), &["approve(address,uint256)".to_owned()]);
+    assert_eq!(db.lookup_function(&[0x38, 0xed, 0x17, 0x39]), &["swapExactTokensForTokens(uint256,uint256,address[],address,uint256)".to_owned()]);
+    assert_eq!(db.lookup_function(&[0x25, 0x2d, 0xba, 0x42]), &["aggregate((address,bytes)[])".to_owned()]);
+    assert_eq!(db.lookup_function(&[0x82, 0xad, 0x56, 0xcb]), &["aggregate3((address,bool,bytes)[])".to_owned()]);
+    assert_eq!(db.lookup_error(&[0x08, 0xc3, 0x79, 0xa0]), &["Error(string)".to_owned()]);
+    assert_eq!(db.lookup_event(&event_topic("Transfer(address,address,uint256)")).len(), 1);
+    assert!(db.lookup_function(&[0x8b, 0xe6, 0x52, 0x46]).is_empty());
+
+    // import from ABI, duplicated signature is not added twice
+    let count = db.import_abi_text(ABI).unwrap();
+    assert_eq!(count, 3);
+    assert_eq!(db.lookup_function(&[0x8b, 0xe6, 0x52, 0x46]), &["f(uint256,uint32[],bytes10,bytes)".to_owned()]);
+    assert_eq!(db.lookup_function(&[0xa9, 0x05, 0x9c, 0xbb]).len(), 1);
+
+    // item with unsupported type is skipped, and not counted
+    let count = db.import_abi_text(r#"[
+        {"inputs":[{"name":"x","type":"uint7"}],"name":"h","outputs":[],"stateMutability":"nonpayable","type":"function"},
+        {"inputs":[],"name":"pause","outputs":[],"stateMutability":"nonpayable","type":"function"}
+    ]"#).unwrap();
+    assert_eq!(count, 1);
+    assert_eq!(db.lookup_function(&function_selector("pause()")), &["pause()".to_owned()]);
+}
+
+#[test]
+fn test_decode_function_input_by_signatures() {
+    let mut db = EvmSignatureDatabase::create();
+    db.add_function_signature("g(uint256[][],string[])").unwrap();
+
+    let call = decode_function_input_by_signatures(&db, G_INPUT).unwrap().unwrap();
+    assert_eq!(call.name, "g");
+    assert_eq!(call.signature, "g(uint256[][],string[])");
+    assert_eq!(call.inputs.len(), 2);
+    assert_eq!(call.inputs[0].name, "");
+
+    // unknown selector
+    assert_eq!(decode_function_input_by_signatures(&db, F_INPUT).unwrap(), None);
+}
+
+/// Constructor's arguments of `constructor(address owner, string name, uint256[] caps)`
+/// with values `(0x00..aa, "Vault", [1, 2])` as returned from API.
+static CONSTRUCTOR_ARGS: &str = concat!(
+    "00000000000000000000000000000000000000000000000000000000000000aa",
+    "0000000000000000000000000000000000000000000000000000000000000060",
+    "00000000000000000000000000000000000000000000000000000000000000a0",
+    "0000000000000000000000000000000000000000000000000000000000000005",
+    "5661756c74000000000000000000000000000000000000000000000000000000",
+    "0000000000000000000000000000000000000000000000000000000000000002",
+    "0000000000000000000000000000000000000000000000000000000000000001",
+    "0000000000000000000000000000000000000000000000000000000000000002",
+);
+
+static CONSTRUCTOR_ABI: &str = r#"[
+    {"inputs":[{"name":"owner","type":"address"},{"name":"name","type":"string"},{"name":"caps","type":"uint256[]"}],"stateMutability":"nonpayable","type":"constructor"}
+]"#;
+
+/// Create contract's metadata with the specified ABI, and constructor's arguments
+/// going through the same deserialization as API response.
+fn contract_with_constructor_args(abi: &str, args: &str) -> EvmContractSourceCode {
+    let mut record = source_code_record("contract Vault {}");
+    record.abi = abi.to_owned();
+    record.constructor_arguments = de_constructor_arguments_string_to_vec_string(serde_json::Value::String(args.to_owned())).unwrap();
+    record
+}
+
+#[test]
+fn test_decode_constructor_arguments() {
+    let contract = contract_with_constructor_args(CONSTRUCTOR_ABI, CONSTRUCTOR_ARGS);
+    let args = contract.decode_constructor_arguments().unwrap();
+    assert_eq!(args.len(), 3);
+    assert_eq!(args[0].name, "owner");
+    assert_eq!(args[0].value, EvmAbiValue::Address(H160::from_low_u64_be(0xaa)));
+    assert_eq!(args[1].name, "name");
+    assert_eq!(args[1].value, EvmAbiValue::String("Vault".to_owned()));
+    assert_eq!(args[2].param_type, EvmAbiParamType::Array(Box::new(EvmAbiParamType::Uint(256))));
+    assert_eq!(args[2].value, EvmAbiValue::Array(vec![EvmAbiValue::Uint(U256::from(1)), EvmAbiValue::Uint(U256::from(2))]));
+
+    // no constructor, and no arguments
+    let contract = contract_with_constructor_args("[]", "");
+    assert!(contract.decode_constructor_arguments().unwrap().is_empty());
+}
+
+#[test]
+fn test_decode_constructor_arguments_malformed() {
+    // trailing partial word is kept instead of dropped, then rejected
+    let contract = contract_with_constructor_args(CONSTRUCTOR_ABI, &format!("{}abcd", CONSTRUCTOR_ARGS));
+    assert_eq!(contract.constructor_arguments.len(), 9);
+    assert_eq!(contract.constructor_arguments[8], "abcd");
+    assert!(contract.decode_constructor_arguments().is_err());
+
+    // length of string goes beyond the data
+    let truncated = &CONSTRUCTOR_ARGS[..64 * 4];
+    let contract = contract_with_constructor_args(CONSTRUCTOR_ABI, truncated);
+    assert!(contract.decode_constructor_arguments().is_err());
+
+    // arguments without constructor
+    let contract = contract_with_constructor_args("[]", &CONSTRUCTOR_ARGS[..64]);
+    assert!(contract.decode_constructor_arguments().is_err());
+}
+
+#[test]
+fn test_encode_function_call_roundtrip() {
+    let abi = Contracts::parse_abi(ABI).unwrap();
+
+    // re-encoding decoded input must give back exactly the same bytes
+    for input in [F_INPUT, G_INPUT] {
+        let call = decode_function_input(&abi, input).unwrap().unwrap();
+        let args: Vec<EvmAbiValue> = call.inputs.iter().map(|i| i.value.clone()).collect();
+
+        let encoded = encode_function_call(&call.signature, &args).unwrap();
+        assert_eq!(encode_hex(&encoded), input);
+
+        let (encoded, item) = encode_function_call_from_abi(&abi, &call.name, &args).unwrap();
+        assert_eq!(encode_hex(&encoded), input);
+        assert_eq!(item.name.as_deref(), Some(call.name.as_str()));
+    }
+}
+
+#[test]
+fn test_encode_function_call_signature() {
+    let owner = H160::from_low_u64_be(0xaa);
+    let encoded = encode_function_call("balanceOf(address)(uint256)", &[EvmAbiValue::Address(owner)]).unwrap();
+    assert_eq!(encode_hex(&encoded), "0x70a0823100000000000000000000000000000000000000000000000000000000000000aa");
+
+    let (input, outputs) = parse_call_signature("getReserves()(uint112,uint112,uint32)").unwrap();
+    assert_eq!(input, "getReserves()");
+    assert_eq!(outputs, Some(vec![EvmAbiParamType::Uint(112), EvmAbiParamType::Uint(112), EvmAbiParamType::Uint(32)]));
+    assert_eq!(parse_call_signature("decimals()").unwrap(), ("decimals()", None));
+
+    // negative int is sign-extended
+    let encoded = encode(&[EvmAbiParamType::Int(8)], &[EvmAbiValue::Int(I256::from_i128(-1))]).unwrap();
+    assert_eq!(encoded, vec![0xff; 32]);
+}
+
+#[test]
+fn test_encode_mismatch() {
+    let abi = Contracts::parse_abi(ABI).unwrap();
+
+    assert!(encode(&[EvmAbiParamType::Uint(8)], &[EvmAbiValue::Uint(U256::from(256))]).is_err());
+    assert!(encode(&[EvmAbiParamType::Int(8)], &[EvmAbiValue::Int(I256::from_i128(128))]).is_err());
+    assert!(encode(&[EvmAbiParamType::Address], &[EvmAbiValue::Bool(true)]).is_err());
+    assert!(encode(&[EvmAbiParamType::FixedBytes(4)], &[EvmAbiValue::FixedBytes(vec![1, 2])]).is_err());
+    assert!(encode(&[EvmAbiParamType::Bool], &[]).is_err());
+    assert!(encode_function_call_from_abi(&abi, "transfer", &[EvmAbiValue::Bool(true)]).is_err());
+    assert!(encode_function_call_from_abi(&abi, "approve", &[]).is_err());
+}
+
+#[test]
+fn test_encode_function_call_from_abi_skips_unsupported_overload() {
+    let abi = Contracts::parse_abi(r#"[
+        {"inputs":[{"name":"x","type":"uint7"}],"name":"set","outputs":[],"stateMutability":"nonpayable","type":"function"},
+        {"inputs":[{"name":"x","type":"uint256"}],"name":"set","outputs":[],"stateMutability":"nonpayable","type":"function"}
+    ]"#).unwrap();
+
+    let (encoded, item) = encode_function_call_from_abi(&abi, "set", &[EvmAbiValue::Uint(U256::from(1))]).unwrap();
+    assert_eq!(encoded[..4], function_selector("set(uint256)")[..]);
+    assert_eq!(item.inputs.as_ref().unwrap()[0].r#type, "uint256");
+}
+
+#[test]
+fn test_decode_function_output() {
+    let abi = Contracts::parse_abi(ABI).unwrap();
+    let transfer = abi.iter().find(|item| item.name.as_deref() == Some("transfer")).unwrap();
+
+    let data = decode_hex("0x0000000000000000000000000000000000000000000000000000000000000001").unwrap();
+    let outputs = decode_function_output(transfer, &data).unwrap();
+    assert_eq!(outputs.len(), 1);
+    assert_eq!(outputs[0].value, EvmAbiValue::Bool(true));
+}
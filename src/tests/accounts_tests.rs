@@ -0,0 +1,122 @@
+/**
+ * Tests for Accounts related functionality which operate on already fetched
+ * data, thus no API key, or network access is needed.
+ */
+#[cfg(test)]
+use crate::prelude::*;
+use crate::types::evm_types::*;
+use crate::api::accounts::Accounts;
+
+static ADDRESS: &str = "0xAbC0000000000000000000000000000000000001";
+static OTHER_ADDRESS: &str = "0x00000000000000000000000000000000000000ff";
+
+fn normal_tx(block_number: u64, from: &str, to: &str, value: u64, gas_used: u64, gas_price: u64, is_error: bool) -> EvmNormalTransactionResponseSuccessVariantResult {
+    let json = format!(r#"{{"blockNumber":"{block_number}","timeStamp":"{timestamp}","hash":"0x01","nonce":"0","transactionIndex":"0","from":"{from}","to":"{to}","value":"{value}","gas":"21000","gasPrice":"{gas_price}","isError":"{is_error}","txreceipt_status":"1","input":"0x","contractAddress":"","cumulativeGasUsed":"0","gasUsed":"{gas_used}","confirmations":"1"}}"#,
+        block_number=block_number, timestamp=block_number * 3, from=from, to=to, value=value, gas_price=gas_price, is_error=if is_error { 1 } else { 0 }, gas_used=gas_used);
+    serde_json::from_str(&json).unwrap()
+}
+
+fn internal_tx(block_number: u64, from: &str, to: &str, value: u64, is_error: bool) -> EvmInternalTransactionResponseSuccessVariantResult {
+    let json = format!(r#"{{"blockNumber":"{block_number}","timeStamp":"{timestamp}","hash":"0x02","from":"{from}","to":"{to}","value":"{value}","contractAddress":"","input":"","type":"call","gas":"0","gasUsed":"0","traceId":"0","isError":"{is_error}","errCode":""}}"#,
+        block_number=block_number, timestamp=block_number * 3, from=from, to=to, value=value, is_error=if is_error { 1 } else { 0 });
+    serde_json::from_str(&json).unwrap()
+}
+
+#[test]
+fn test_reconstruct_native_balance_history() {
+    let normal_txs = vec![
+        normal_tx(10, OTHER_ADDRESS, ADDRESS, 1_000_000, 21_000, 10, false),
+        // failed transaction only costs gas fee
+        normal_tx(12, &ADDRESS.to_lowercase(), OTHER_ADDRESS, 500_000, 30_000, 10, true),
+        normal_tx(15, ADDRESS, OTHER_ADDRESS, 100_000, 21_000, 10, false),
+    ];
+    let internal_txs = vec![
+        internal_tx(12, OTHER_ADDRESS, ADDRESS, 50_000, false),
+        internal_tx(13, OTHER_ADDRESS, ADDRESS, 70_000, true),
+    ];
+
+    let history = Accounts::reconstruct_native_balance_history(ADDRESS, &normal_txs, &internal_txs);
+
+    assert_eq!(history.points.len(), 3);    // failed internal transaction doesn't count
+    assert!(!history.is_underflow);
+    assert_eq!(history.reported_balance, None);
+    assert!(!history.is_discrepant());
+
+    assert_eq!(history.points[0].block_number, 10);
+    assert_eq!(history.points[0].timestamp, 30);
+    assert_eq!(history.points[0].balance, U256::from(1_000_000u64));
+
+    // both transactions in block 12 are aggregated
+    assert_eq!(history.points[1].block_number, 12);
+    assert_eq!(history.points[1].value_in, U256::from(50_000u64));
+    assert_eq!(history.points[1].value_out, U256::zero());
+    assert_eq!(history.points[1].fee, U256::from(300_000u64));
+    assert_eq!(history.points[1].balance, U256::from(750_000u64));
+
+    assert_eq!(history.points[2].value_out, U256::from(100_000u64));
+    assert_eq!(history.points[2].fee, U256::from(210_000u64));
+    assert_eq!(history.points[2].balance, U256::from(440_000u64));
+    assert_eq!(history.reconstructed_balance, U256::from(440_000u64));
+}
+
+#[test]
+fn test_reconstruct_native_balance_history_discrepancy() {
+    let normal_txs = vec![
+        normal_tx(10, ADDRESS, OTHER_ADDRESS, 1_000, 21_000, 1, false),
+    ];
+
+    let mut history = Accounts::reconstruct_native_balance_history(ADDRESS, &normal_txs, &[]);
+    assert!(history.is_underflow);
+    assert_eq!(history.reconstructed_balance, U256::zero());
+
+    history.reported_balance = Some(U256::zero());
+    assert!(history.is_discrepant());
+}
+
+fn transfer_event(block_number: u64, contract_address: &str, symbol: &str, decimal: u8, from: &str, to: &str, value: u64) -> EvmErc20TokenTransferEventResponseSuccessVariantResult {
+    let json = format!(r#"{{"blockNumber":"{block_number}","timeStamp":"{timestamp}","hash":"0x03","nonce":"0","blockHash":"0x04","from":"{from}","contractAddress":"{contract_address}","to":"{to}","value":"{value}","tokenName":"{symbol} Token","tokenSymbol":"{symbol}","tokenDecimal":"{decimal}","transactionIndex":"0","gas":"0","gasPrice":"0","gasUsed":"0","cumulativeGasUsed":"0","input":"deprecated","confirmations":"1"}}"#,
+        block_number=block_number, timestamp=block_number * 3, from=from, contract_address=contract_address, to=to, value=value, symbol=symbol, decimal=decimal);
+    serde_json::from_str(&json).unwrap()
+}
+
+#[test]
+fn test_reconstruct_erc20_holdings() {
+    let token_a = "0x00000000000000000000000000000000000000aa";
+    let token_b = "0x00000000000000000000000000000000000000bb";
+
+    let events = vec![
+        transfer_event(20, token_b, "TKB", 2, OTHER_ADDRESS, ADDRESS, 1_000),
+        transfer_event(10, token_a, "TKA", 6, OTHER_ADDRESS, ADDRESS, 5_000_000),
+        transfer_event(20, &token_a.to_uppercase().replace("0X", "0x"), "TKA", 6, ADDRESS, OTHER_ADDRESS, 1_500_000),
+        transfer_event(30, token_a, "TKA", 6, ADDRESS, OTHER_ADDRESS, 500_000),
+    ];
+
+    let snapshot = Accounts::reconstruct_erc20_holdings(ADDRESS, &events, None);
+    assert_eq!(snapshot.block_number, None);
+    assert_eq!(snapshot.holdings.len(), 2);
+
+    let holding_a = &snapshot.holdings[0];
+    assert_eq!(holding_a.token_symbol, "TKA");
+    assert_eq!(holding_a.token_name, "TKA Token");
+    assert_eq!(holding_a.token_decimal, 6);
+    assert_eq!(holding_a.total_in, U256::from(5_000_000u64));
+    assert_eq!(holding_a.total_out, U256::from(2_000_000u64));
+    assert_eq!(holding_a.balance, U256::from(3_000_000u64));
+    assert_eq!(holding_a.balance_f, 3.0);
+    assert!(!holding_a.is_underflow);
+    assert_eq!(holding_a.points.len(), 3);
+    assert_eq!(holding_a.points[1].block_number, 20);
+    assert_eq!(holding_a.points[1].balance_f, 3.5);
+
+    let holding_b = &snapshot.holdings[1];
+    assert_eq!(holding_b.balance_f, 10.0);
+
+    // as of block in the middle
+    let snapshot = Accounts::reconstruct_erc20_holdings(ADDRESS, &events, Some(20));
+    assert_eq!(snapshot.block_number, Some(20));
+    assert_eq!(snapshot.holdings[0].balance, U256::from(3_500_000u64));
+    assert_eq!(snapshot.holdings[0].points.len(), 2);
+
+    let snapshot = Accounts::reconstruct_erc20_holdings(ADDRESS, &events, Some(10));
+    assert_eq!(snapshot.holdings.len(), 1);
+}
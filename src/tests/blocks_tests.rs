@@ -0,0 +1,57 @@
+/**
+ * Tests for Blocks related functionality which operate on already fetched API
+ * responses, thus no API key, or network access is needed.
+ */
+#[cfg(test)]
+use crate::prelude::*;
+use crate::types::evm_types::*;
+use crate::api::blocks::Blocks;
+
+#[test]
+fn test_block_reward_from_response() {
+    let text = r#"{"status":"1","message":"OK","result":{
+        "blockNumber":"2165403",
+        "timeStamp":"1472533979",
+        "blockMiner":"0x13a06d3dfe21e0db5c016c03ea7d2509f7f8d1e3",
+        "blockReward":"5314181600000000000",
+        "uncles":[
+            {"miner":"0xbcdfc35b86bedf72f0cda046a3c16829a2ef41d1","unclePosition":"0","blockreward":"3750000000000000000"},
+            {"miner":"0x0d0c9855c722ff0c78f21e43aa275a5b8ea60dce","unclePosition":"1","blockreward":"3750000000000000000"}
+        ],
+        "uncleInclusionReward":"312500000000000000"
+    }}"#;
+    let reward = Blocks::block_reward_from_response(serde_json::from_str::<EvmBlockRewardResponse>(text).unwrap()).unwrap();
+    assert_eq!(reward.block_number, 2165403);
+    assert_eq!(reward.timestamp, 1472533979);
+    assert_eq!(reward.block_reward, U256::from(5_314_181_600_000_000_000u64));
+    assert_eq!(reward.uncles.len(), 2);
+    assert_eq!(reward.uncles[1].uncle_position, 1);
+    assert_eq!(reward.uncles[1].block_reward, U256::from(3_750_000_000_000_000_000u64));
+    assert_eq!(reward.uncle_inclusion_reward, U256::from(312_500_000_000_000_000u64));
+}
+
+#[test]
+fn test_block_countdown_from_response() {
+    let text = r#"{"status":"1","message":"OK","result":{"CurrentBlock":"12715477","CountdownBlock":"16701588","RemainingBlock":"3986111","EstimateTimeInSec":"52616680.2"}}"#;
+    let countdown = Blocks::block_countdown_from_response(serde_json::from_str::<EvmBlockCountdownResponse>(text).unwrap()).unwrap();
+    assert_eq!(countdown.current_block, 12715477);
+    assert_eq!(countdown.countdown_block, 16701588);
+    assert_eq!(countdown.remaining_block, 3986111);
+    assert_eq!(countdown.estimate_time_in_sec, 52616680.2);
+
+    let text = r#"{"status":"0","message":"NOTOK","result":"Error! Block number already pass"}"#;
+    match Blocks::block_countdown_from_response(serde_json::from_str::<EvmBlockCountdownResponse>(text).unwrap()) {
+        Err(EvmError::ErrorApiResponse(msg)) => assert!(msg.contains("already pass")),
+        res => panic!("unexpected result {:?}", res),
+    }
+}
+
+#[test]
+fn test_block_number_from_response() {
+    let parse = |text: &str| Blocks::block_number_from_response(serde_json::from_str::<EvmBlockNumberResponse>(text).unwrap());
+
+    assert_eq!(parse(r#"{"status":"1","message":"OK","result":"12712551"}"#).unwrap(), 12712551);
+    assert!(parse(r#"{"status":"0","message":"NOTOK","result":"Error! No closest block found"}"#).is_err());
+    assert_eq!(EvmBlockClosest::Before.as_str(), "before");
+    assert_eq!(EvmBlockClosest::After.as_str(), "after");
+}
@@ -8,6 +8,8 @@
  */
 #[cfg(test)]
 use crate::prelude::*;
+use crate::types::evm_types::{EvmContractABIItemKind, EvmBlockParameter, EvmLogFilter};
+use crate::types::abi_types::EvmAbiValue;
 use crate::environ::Context;
 use crate::evmscan;
 use lazy_static::lazy_static;
@@ -96,6 +98,17 @@ serial_test! {
     }
 }
 
+serial_test! {
+    fn test_get_native_balance_history() {
+        let ctx = Context::create(ChainType::BSC, get_api_key_or_panic());
+
+        let history = evmscan::accounts().get_native_balance_history(&ctx, &ADDRESS2, false).unwrap();
+        assert!(!history.points.is_empty());
+        assert!(history.reported_balance.is_some());
+        assert_eq!(history.points.last().unwrap().balance, history.reconstructed_balance);
+    }
+}
+
 // TODO: separate this Stats related API into separate test file
 serial_test! {
     fn test_get_native_token_last_price() {
@@ -116,7 +129,7 @@ serial_test! {
         let res = evmscan::contracts().get_abi(&ctx, &ADDRESS1, false);
         assert!(res.is_ok());
         assert!(res.unwrap().len() == 11842);   // exact number of character
-                                                // from cleaned '\' char
+                                                // of ABI as verified
     }
 }
 
@@ -130,6 +143,16 @@ serial_test! {
     }
 }
 
+serial_test! {
+    fn test_contracts_get_abi_typed() {
+        let ctx = Context::create(ChainType::BSC, get_api_key_or_panic());
+
+        let abi = evmscan::contracts().get_abi_typed(&ctx, &ADDRESS1).unwrap();
+        assert!(!abi.is_empty());
+        assert!(abi.iter().any(|item| item.r#type == EvmContractABIItemKind::Event));
+    }
+}
+
 serial_test! {
     fn test_contracts_get_verified_source_code() {
         let ctx = Context::create(ChainType::BSC, get_api_key_or_panic());
@@ -203,3 +226,230 @@ serial_test! {
         }
     }
 }
+
+serial_test! {
+    fn test_contracts_get_verified_source_code_typed() {
+        let ctx = Context::create(ChainType::BSC, get_api_key_or_panic());
+
+        // same address as of `test_contracts_get_verified_source_code_json_format()`
+        match evmscan::contracts().get_verified_source_code_typed(&ctx, "0x1befe6f3f0e8edd2d4d15cae97baee01e51ea4a4") {
+            Err(e) => panic!("{}", e),
+            Ok(res) => {
+                assert!(res.is_standard_json);
+                assert_eq!(res.sources.len(), 6);
+                assert_eq!(res.sources[0].path, "contracts/LpMigration.sol");
+                assert_eq!(res.contract.compiler_version, "v0.8.12+commit.f00d7308");
+            }
+        }
+    }
+}
+
+serial_test! {
+    fn test_contracts_get_contract_creation() {
+        let ctx = Context::create(ChainType::BSC, get_api_key_or_panic());
+
+        // more than a single batch of 5 addresses
+        let addresses = ["0x1befe6f3f0e8edd2d4d15cae97baee01e51ea4a4"; 6];
+        match evmscan::contracts().get_contract_creation(&ctx, &addresses, 5) {
+            Err(e) => panic!("{}", e),
+            Ok(res) => {
+                assert!(!res.is_empty());
+                assert!(res.iter().all(|c| c.contract_address.eq_ignore_ascii_case(addresses[0])));
+                assert!(res[0].tx_hash.starts_with("0x"));
+            }
+        }
+    }
+}
+
+serial_test! {
+    fn test_proxy_get_block() {
+        let ctx = Context::create(ChainType::BSC, get_api_key_or_panic());
+        let proxy = evmscan::proxy();
+
+        let latest = match proxy.get_block_number(&ctx) {
+            Err(e) => panic!("{}", e),
+            Ok(res) => res,
+        };
+        assert!(latest > 0);
+
+        match proxy.get_block_by_number(&ctx, EvmBlockParameter::Number(latest)) {
+            Err(e) => panic!("{}", e),
+            Ok(res) => {
+                let block = res.expect("block must exist");
+                assert_eq!(block.number, Some(latest));
+
+                match proxy.get_block_transaction_count_by_number(&ctx, EvmBlockParameter::Number(latest)) {
+                    Err(e) => panic!("{}", e),
+                    Ok(count) => assert_eq!(count, Some(block.transactions.len() as u64)),
+                }
+            }
+        }
+    }
+}
+
+serial_test! {
+    fn test_proxy_get_transaction_and_receipt() {
+        let ctx = Context::create(ChainType::BSC, get_api_key_or_panic());
+        let proxy = evmscan::proxy();
+
+        // BSC's genesis-era blocks have no transaction, so look at a recent one
+        let latest = match proxy.get_block_number(&ctx) {
+            Err(e) => panic!("{}", e),
+            Ok(res) => res,
+        };
+        let tx = match proxy.get_transaction_by_block_number_and_index(&ctx, EvmBlockParameter::Number(latest - 10), 0) {
+            Err(e) => panic!("{}", e),
+            Ok(res) => res.expect("transaction must exist"),
+        };
+        assert_eq!(tx.block_number, Some(latest - 10));
+        assert_eq!(tx.transaction_index, Some(0));
+
+        match proxy.get_transaction_by_hash(&ctx, &tx.hash) {
+            Err(e) => panic!("{}", e),
+            Ok(res) => assert_eq!(res.expect("transaction must exist").hash, tx.hash),
+        }
+
+        match proxy.get_transaction_receipt(&ctx, &tx.hash) {
+            Err(e) => panic!("{}", e),
+            Ok(res) => {
+                let receipt = res.expect("receipt must exist");
+                assert_eq!(receipt.transaction_hash, tx.hash);
+                assert!(receipt.is_success().is_some());
+            }
+        }
+    }
+}
+
+serial_test! {
+    fn test_proxy_call_function() {
+        let ctx = Context::create(ChainType::BSC, get_api_key_or_panic());
+
+        // WBNB
+        match evmscan::proxy().call_function(&ctx, "0xbb4CdB9CBd36B01bD1cBaEBF2De08d9173bc095c", "decimals()(uint8)", &[], EvmBlockParameter::Latest) {
+            Err(e) => panic!("{}", e),
+            Ok(res) => assert_eq!(res, vec![EvmAbiValue::Uint(U256::from(18))]),
+        }
+    }
+}
+
+serial_test! {
+    fn test_proxy_is_contract() {
+        let ctx = Context::create(ChainType::BSC, get_api_key_or_panic());
+        let proxy = evmscan::proxy();
+
+        // WBNB
+        match proxy.is_contract(&ctx, "0xbb4CdB9CBd36B01bD1cBaEBF2De08d9173bc095c") {
+            Err(e) => panic!("{}", e),
+            Ok(res) => assert!(res),
+        }
+        match proxy.get_transaction_count(&ctx, "0x0000000000000000000000000000000000000000", EvmBlockParameter::Latest) {
+            Err(e) => panic!("{}", e),
+            Ok(res) => assert_eq!(res, 0),
+        }
+    }
+}
+
+serial_test! {
+    fn test_proxy_gas() {
+        let ctx = Context::create(ChainType::BSC, get_api_key_or_panic());
+        let proxy = evmscan::proxy();
+
+        match proxy.get_gas_price(&ctx) {
+            Err(e) => panic!("{}", e),
+            Ok(res) => assert!(res > U256::zero()),
+        }
+        // plain transfer to EOA costs at least 21000 gas; avoid low addresses
+        // as they are precompiles which charge extra
+        match proxy.estimate_gas(&ctx, "0x000000000000000000000000000000000000dEaD", &[], None, None, None) {
+            Err(e) => panic!("{}", e),
+            Ok(res) => assert!(res >= U256::from(21000)),
+        }
+    }
+}
+
+serial_test! {
+    fn test_transactions_get_statuses() {
+        let ctx = Context::create(ChainType::BSC, get_api_key_or_panic());
+        let proxy = evmscan::proxy();
+
+        let latest = match proxy.get_block_number(&ctx) {
+            Err(e) => panic!("{}", e),
+            Ok(res) => res,
+        };
+        let tx = match proxy.get_transaction_by_block_number_and_index(&ctx, EvmBlockParameter::Number(latest - 10), 0) {
+            Err(e) => panic!("{}", e),
+            Ok(res) => res.expect("transaction must exist"),
+        };
+
+        match evmscan::transactions().get_statuses(&ctx, &[&tx.hash], 5) {
+            Err(e) => panic!("{}", e),
+            Ok(res) => {
+                assert_eq!(res.len(), 1);
+                let status = res[0].as_ref().unwrap();
+                assert_eq!(status.tx_hash, tx.hash);
+                assert!(status.receipt_status.is_some());
+                assert_eq!(status.execution.is_error, status.receipt_status == Some(false));
+            }
+        }
+    }
+}
+
+serial_test! {
+    fn test_blocks() {
+        let ctx = Context::create(ChainType::BSC, get_api_key_or_panic());
+        let blocks = evmscan::blocks();
+
+        match blocks.get_block_reward(&ctx, 1_000_000) {
+            Err(e) => panic!("{}", e),
+            Ok(res) => {
+                assert_eq!(res.block_number, 1_000_000);
+                assert!(res.uncles.is_empty());
+            }
+        }
+
+        // 2021-01-01T00:00:00Z to 2021-01-01T00:01:00Z
+        match blocks.get_block_range_by_timestamps(&ctx, 1609459200, 1609459260) {
+            Err(e) => panic!("{}", e),
+            Ok((start, end)) => {
+                assert!(start <= end);
+                // BSC mines a block every 3 seconds
+                assert!(end - start <= 20);
+            }
+        }
+    }
+}
+
+serial_test! {
+    fn test_logs_get_logs() {
+        let ctx = Context::create(ChainType::BSC, get_api_key_or_panic());
+
+        // Transfer events of WBNB within a few blocks
+        let mut filter = EvmLogFilter::create(Some("0xbb4CdB9CBd36B01bD1cBaEBF2De08d9173bc095c"), 20_000_000, Some(20_000_002));
+        filter.topics[0] = Some("0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef".to_owned());
+
+        match evmscan::logs().get_logs(&ctx, &filter) {
+            Err(e) => panic!("{}", e),
+            Ok(res) => {
+                assert!(!res.is_empty());
+                assert!(res.iter().all(|log| log.block_number >= 20_000_000 && log.block_number <= 20_000_002));
+                assert!(res.iter().all(|log| log.topics[0] == "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"));
+            }
+        }
+    }
+}
+
+serial_test! {
+    fn test_gastracker_get_gas_oracle() {
+        let ctx = Context::create(ChainType::BSC, get_api_key_or_panic());
+
+        match evmscan::gastracker().get_gas_oracle(&ctx) {
+            Err(e) => panic!("{}", e),
+            Ok(res) => {
+                assert!(res.last_block > 0);
+                assert!(res.safe_gas_price <= res.propose_gas_price);
+                assert!(res.propose_gas_price <= res.fast_gas_price);
+                assert!(res.usd_price.is_some());
+            }
+        }
+    }
+}
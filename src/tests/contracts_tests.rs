@@ -0,0 +1,486 @@
+/**
+ * Tests for Contracts related functionality which operate on already fetched
+ * data, thus no API key, or network access is needed.
+ */
+#[cfg(test)]
+use crate::types::evm_types::*;
+use crate::api::contracts::Contracts;
+
+/// ABI exercising most parts of the specification.
+static FULL_ABI: &str = r#"[
+    {"inputs":[{"internalType":"address","name":"owner","type":"address"}],"stateMutability":"nonpayable","type":"constructor"},
+    {"stateMutability":"payable","type":"receive"},
+    {"stateMutability":"nonpayable","type":"fallback"},
+    {"inputs":[{"internalType":"uint256","name":"available","type":"uint256"},{"internalType":"uint256","name":"required","type":"uint256"}],"name":"InsufficientBalance","type":"error"},
+    {"anonymous":false,"inputs":[{"indexed":true,"internalType":"address","name":"from","type":"address"},{"indexed":false,"internalType":"struct Pool.Order[]","name":"orders","type":"tuple[]","components":[{"internalType":"address","name":"maker","type":"address"},{"internalType":"uint256[2]","name":"amounts","type":"uint256[2]"},{"internalType":"struct Pool.Fee","name":"fee","type":"tuple","components":[{"internalType":"uint16","name":"bps","type":"uint16"},{"internalType":"address","name":"recipient","type":"address"}]}]}],"name":"OrdersFilled","type":"event"},
+    {"inputs":[{"components":[{"internalType":"address","name":"maker","type":"address"},{"internalType":"bytes","name":"data","type":"bytes"}],"internalType":"struct Pool.Call","name":"call","type":"tuple"}],"name":"execute","outputs":[{"internalType":"bytes[]","name":"","type":"bytes[]"}],"stateMutability":"payable","type":"function"},
+    {"constant":true,"inputs":[],"name":"totalSupply","outputs":[{"name":"","type":"uint256"}],"payable":false,"stateMutability":"view","type":"function"},
+    {"name":"set_owner","inputs":[{"type":"address","name":"_owner"}],"outputs":[],"gas":36245}
+]"#;
+
+#[test]
+fn test_parse_abi_typed() {
+    let abi = Contracts::parse_abi(FULL_ABI).unwrap();
+    assert_eq!(abi.len(), 8);
+
+    assert_eq!(abi[0].r#type, EvmContractABIItemKind::Constructor);
+    assert_eq!(abi[1].r#type, EvmContractABIItemKind::Receive);
+    assert_eq!(abi[1].state_mutability, Some(EvmContractStateMutability::Payable));
+    assert_eq!(abi[2].r#type, EvmContractABIItemKind::Fallback);
+    assert_eq!(abi[3].r#type, EvmContractABIItemKind::Error);
+    assert_eq!(abi[3].name.as_deref(), Some("InsufficientBalance"));
+
+    assert_eq!(abi[4].r#type, EvmContractABIItemKind::Event);
+    let event_inputs = abi[4].inputs.as_ref().unwrap();
+    assert_eq!(event_inputs[0].indexed, Some(true));
+    let components = event_inputs[1].components.as_ref().unwrap();
+    assert_eq!(components.len(), 3);
+    assert_eq!(components[2].r#type, "tuple");
+    assert_eq!(components[2].components.as_ref().unwrap()[1].name, "recipient");
+
+    assert_eq!(abi[6].constant, Some(true));
+    assert_eq!(abi[6].payable, Some(false));
+
+    // type is omitted, so it defaults to function
+    assert_eq!(abi[7].r#type, EvmContractABIItemKind::Function);
+    assert_eq!(abi[7].other.get("gas"), Some(&serde_json::json!(36245)));
+}
+
+#[test]
+fn test_parse_abi_typed_lossless() {
+    let abi = Contracts::parse_abi(FULL_ABI).unwrap();
+    let pretty = serde_json::to_string_pretty(&abi).unwrap();
+
+    let mut expected: serde_json::Value = serde_json::from_str(FULL_ABI).unwrap();
+    // the only addition is the explicit `type` for item which omitted it
+    expected[7]["type"] = serde_json::json!("function");
+
+    let actual: serde_json::Value = serde_json::from_str(&pretty).unwrap();
+    assert_eq!(actual, expected);
+}
+
+/// Standard JSON input wrapped with extra pair of braces as returned from API.
+/// Its content contains nested braces, quotes, and escape sequences.
+pub(crate) static STANDARD_JSON_SOURCE: &str = r#"{{
+  "language": "Solidity",
+  "sources": {
+    "contracts/Vault.sol": {
+      "content": "// SPDX-License-Identifier: MIT\npragma solidity ^0.8.0;\n\nimport \"@openzeppelin/contracts/access/Ownable.sol\";\n\ncontract Vault is Ownable {\n    struct Info { uint256 a; }\n    function f() external pure returns (string memory) { return \"}\\\"{\"; }\n}\n"
+    },
+    "@openzeppelin/contracts/access/Ownable.sol": {
+      "content": "pragma solidity ^0.8.0;\nabstract contract Ownable {}\n"
+    }
+  },
+  "settings": {
+    "optimizer": { "enabled": true, "runs": 999999, "details": { "yul": true } },
+    "evmVersion": "london",
+    "viaIR": true,
+    "remappings": ["@openzeppelin/=lib/openzeppelin-contracts/"],
+    "libraries": { "contracts/Lib.sol": { "Lib": "0x00000000000000000000000000000000000000aa" } },
+    "outputSelection": { "*": { "*": ["abi", "evm.bytecode"] } },
+    "metadata": { "bytecodeHash": "none" }
+  }
+}}"#;
+
+#[test]
+fn test_parse_source_code_standard_json() {
+    let input = Contracts::parse_source_code(STANDARD_JSON_SOURCE).unwrap().unwrap();
+    assert_eq!(input.language, "Solidity");
+
+    assert_eq!(input.sources.len(), 2);
+    assert_eq!(input.sources[0].path, "contracts/Vault.sol");
+    assert!(input.sources[0].content.contains("import \"@openzeppelin/contracts/access/Ownable.sol\";\n"));
+    assert!(input.sources[0].content.contains(r#"return "}\"{";"#));
+    assert_eq!(input.sources[1].path, "@openzeppelin/contracts/access/Ownable.sol");
+
+    let settings = input.settings.unwrap();
+    let optimizer = settings.optimizer.unwrap();
+    assert_eq!(optimizer.enabled, Some(true));
+    assert_eq!(optimizer.runs, Some(999999));
+    assert_eq!(optimizer.details, Some(serde_json::json!({ "yul": true })));
+    assert_eq!(settings.evm_version.as_deref(), Some("london"));
+    assert_eq!(settings.via_ir, Some(true));
+    assert_eq!(settings.remappings, vec!["@openzeppelin/=lib/openzeppelin-contracts/".to_owned()]);
+    assert_eq!(settings.libraries["contracts/Lib.sol"]["Lib"], "0x00000000000000000000000000000000000000aa");
+    assert!(settings.other.contains_key("outputSelection"));
+    assert!(settings.other.contains_key("metadata"));
+}
+
+#[test]
+fn test_parse_source_code_sources_map() {
+    let source = r#"{"b/B.sol":{"content":"contract B { }"},"a/A.sol":{"content":"import \"../b/B.sol\";\ncontract A { }"}}"#;
+    let input = Contracts::parse_source_code(source).unwrap().unwrap();
+
+    assert_eq!(input.language, "Solidity");
+    assert_eq!(input.settings, None);
+    // order is kept as it appears
+    assert_eq!(input.sources[0].path, "b/B.sol");
+    assert_eq!(input.sources[1].path, "a/A.sol");
+    assert_eq!(input.sources[1].content, "import \"../b/B.sol\";\ncontract A { }");
+}
+
+#[test]
+fn test_parse_source_code_plain() {
+    assert_eq!(Contracts::parse_source_code("pragma solidity 0.6.4;\ncontract A {}").unwrap(), None);
+    assert!(Contracts::parse_source_code("{{ \"sources\": { broken").is_err());
+}
+
+/// Create contract's metadata as returned from API with the specified source code.
+pub(crate) fn source_code_record(source_code: &str) -> EvmContractSourceCode {
+    serde_json::from_value(source_code_record_json(source_code, "[]")).unwrap()
+}
+
+/// Create contract's metadata in JSON the same as returned by API.
+fn source_code_record_json(source_code: &str, abi: &str) -> serde_json::Value {
+    serde_json::json!({
+        "SourceCode": source_code,
+        "ABI": abi,
+        "ContractName": "Vault",
+        "CompilerVersion": "v0.8.12+commit.f00d7308",
+        "OptimizationUsed": "1",
+        "Runs": "200",
+        "ConstructorArguments": "",
+        "EVMVersion": "Default",
+        "Library": "",
+        "LicenseType": "MIT",
+        "Proxy": "0",
+        "Implementation": "",
+        "SwarmSource": "",
+    })
+}
+
+#[test]
+fn test_split_verified_source() {
+    let verified = Contracts::split_verified_source(source_code_record(STANDARD_JSON_SOURCE)).unwrap();
+    assert!(verified.is_standard_json);
+    assert_eq!(verified.sources.len(), 2);
+    assert!(verified.settings.is_some());
+    assert_eq!(verified.contract.source_code, STANDARD_JSON_SOURCE);
+
+    let verified = Contracts::split_verified_source(source_code_record("contract Vault {}")).unwrap();
+    assert!(!verified.is_standard_json);
+    assert_eq!(verified.sources, vec![EvmContractSourceFile { path: "Vault.sol".to_owned(), content: "contract Vault {}".to_owned() }]);
+    assert_eq!(verified.settings, None);
+}
+
+/// Corpus of tricky source code which must be kept byte-exact as verified.
+static TRICKY_SOURCES: &[&str] = &[
+    // escape sequences inside string literal
+    "pragma solidity ^0.8.0;\n\ncontract Signer {\n    function prefixed(bytes32 hash) internal pure returns (bytes32) {\n        return keccak256(abi.encodePacked(\"\\x19Ethereum Signed Message:\\n32\", hash));\n    }\n}\n",
+    // escaped quotes, and backslash
+    "contract Quotes {\n    string public a = \"He said \\\"hi\\\"\";\n    string public b = 'it\\'s';\n    string public c = \"C:\\\\path\\\\to\";\n}\n",
+    // regular expression kept in string
+    "contract Ens {\n    string public constant PATTERN = \"^[a-z0-9-]+\\\\.eth$\";\n    string public constant TAB = \"a\\tb\\rc\";\n}\n",
+    // CRLF line endings, unicode literal, and braces inside comment
+    "// SPDX-License-Identifier: MIT\r\npragma solidity 0.8.12;\r\n/* {{ not json }} */\r\ncontract Unicode {\r\n    string public s = unicode\"Hello 😃 \\u00e9\";\r\n}\r\n",
+];
+
+/// Create API response in JSON text which has the specified source code, and
+/// ABI, escaped the same way as API does.
+fn source_code_response_text(source_code: &str, abi: &str) -> String {
+    serde_json::json!({
+        "status": "1",
+        "message": "OK",
+        "result": [source_code_record_json(source_code, abi)],
+    }).to_string()
+}
+
+#[test]
+fn test_source_code_single_file_is_byte_exact() {
+    for source in TRICKY_SOURCES {
+        let response = serde_json::from_str::<EvmContractSourceCodeResponse>(&source_code_response_text(source, "[]")).unwrap();
+        let records = Contracts::source_code_records_from_response(response).unwrap();
+        let (contracts, is_json) = Contracts::split_source_code_records(records).unwrap();
+
+        assert!(!is_json);
+        assert_eq!(contracts.len(), 1);
+        assert_eq!(contracts[0].source_code, *source);
+    }
+}
+
+#[test]
+fn test_source_code_standard_json_is_byte_exact() {
+    let mut sources = serde_json::Map::new();
+    for (i, source) in TRICKY_SOURCES.iter().enumerate() {
+        sources.insert(format!("contracts/Tricky{}.sol", i), serde_json::json!({ "content": source }));
+    }
+    let input = serde_json::json!({
+        "language": "Solidity",
+        "sources": sources,
+        "settings": { "optimizer": { "enabled": false, "runs": 200 } },
+    });
+    // API wraps Standard JSON input with an extra pair of braces
+    let source_code = format!("{{{}}}", input);
+
+    let response = serde_json::from_str::<EvmContractSourceCodeResponse>(&source_code_response_text(&source_code, "[]")).unwrap();
+    let records = Contracts::source_code_records_from_response(response).unwrap();
+    let (contracts, is_json) = Contracts::split_source_code_records(records).unwrap();
+
+    assert!(is_json);
+    assert_eq!(contracts.len(), 1 + TRICKY_SOURCES.len());
+    assert_eq!(contracts[0].source_code, source_code);
+    for (i, source) in TRICKY_SOURCES.iter().enumerate() {
+        assert_eq!(contracts[i + 1].source_code, *source);
+    }
+}
+
+#[test]
+fn test_abi_text_is_byte_exact() {
+    // `internalType` with backslash is unusual, but must not be touched
+    let abi = r#"[{"inputs":[{"internalType":"string","name":"a\\b","type":"string"}],"name":"f\nx","outputs":[],"stateMutability":"pure","type":"function"}]"#;
+    let response_text = serde_json::json!({ "status": "1", "message": "OK", "result": abi }).to_string();
+    let response = serde_json::from_str::<EvmContractABIResponse>(&response_text).unwrap();
+
+    let abi_text = Contracts::abi_text_from_response(response).unwrap();
+    assert_eq!(abi_text, abi);
+    assert_eq!(Contracts::parse_abi(&abi_text).unwrap()[0].inputs.as_ref().unwrap()[0].name, "a\\b");
+
+    // the same goes for `ABI` field of source code
+    let response = serde_json::from_str::<EvmContractSourceCodeResponse>(&source_code_response_text(TRICKY_SOURCES[0], abi)).unwrap();
+    let records = Contracts::source_code_records_from_response(response).unwrap();
+    assert_eq!(records[0].abi, abi);
+}
+
+#[test]
+fn test_abi_not_verified_is_distinguished() {
+    let error_of = |text: &str| Contracts::abi_text_from_response(serde_json::from_str::<EvmContractABIResponse>(text).unwrap()).unwrap_err();
+
+    assert!(Contracts::is_source_code_not_verified(&error_of(r#"{"status":"0","message":"NOTOK","result":"Contract source code not verified"}"#)));
+    assert!(!Contracts::is_source_code_not_verified(&error_of(r#"{"status":"0","message":"NOTOK","result":"Max rate limit reached"}"#)));
+    assert!(!Contracts::is_source_code_not_verified(&error_of(r#"{"status":"0","message":"NOTOK","result":"Invalid API Key"}"#)));
+    assert!(!Contracts::is_source_code_not_verified(&crate::prelude::EvmError::ErrorApiResponse("Error API response, with HTTP 503 returned".to_owned())));
+}
+
+/// Create contract's metadata of a proxy, or implementation with the specified ABI.
+fn proxy_record(name: &str, abi: &str, implementation: &str) -> EvmContractSourceCode {
+    let mut record = source_code_record(&format!("contract {} {{}}", name));
+    record.contract_name = name.to_owned();
+    record.abi = abi.to_owned();
+    record.proxy = !implementation.is_empty();
+    record.implementation = implementation.to_owned();
+    record
+}
+
+static PROXY_ABI: &str = r#"[
+    {"inputs":[{"name":"logic","type":"address"}],"stateMutability":"nonpayable","type":"constructor"},
+    {"stateMutability":"payable","type":"fallback"},
+    {"inputs":[],"name":"implementation","outputs":[{"name":"","type":"address"}],"stateMutability":"view","type":"function"},
+    {"anonymous":false,"inputs":[{"indexed":true,"name":"implementation","type":"address"}],"name":"Upgraded","type":"event"}
+]"#;
+
+static IMPLEMENTATION_ABI: &str = r#"[
+    {"inputs":[],"stateMutability":"nonpayable","type":"constructor"},
+    {"inputs":[],"name":"implementation","outputs":[{"name":"","type":"address"}],"stateMutability":"pure","type":"function"},
+    {"inputs":[{"name":"to","type":"address"},{"name":"amount","type":"uint256"}],"name":"transfer","outputs":[{"name":"","type":"bool"}],"stateMutability":"nonpayable","type":"function"},
+    {"anonymous":false,"inputs":[{"indexed":true,"name":"implementation","type":"address"}],"name":"Upgraded","type":"event"}
+]"#;
+
+#[test]
+fn test_resolve_proxy_chain() {
+    let res = Contracts::resolve_proxy_with("0xProxy", 5, |address| match address {
+        "0xProxy" => Ok(proxy_record("Proxy", PROXY_ABI, "0xImpl")),
+        "0xImpl" => Ok(proxy_record("Token", IMPLEMENTATION_ABI, "")),
+        _ => panic!("unexpected address {}", address),
+    }).unwrap();
+
+    assert_eq!(res.levels.len(), 2);
+    assert_eq!(res.levels[0].address, "0xProxy");
+    assert_eq!(res.levels[1].address, "0xImpl");
+    assert_eq!(res.levels[1].source.sources[0].path, "Token.sol");
+    assert_eq!(res.levels[1].abi.len(), 4);
+
+    // proxy's constructor, fallback, function, and event, then only `transfer`
+    // from implementation
+    assert_eq!(res.merged_abi.len(), 5);
+    assert_eq!(res.merged_abi[0].inputs.as_ref().unwrap().len(), 1);
+    assert_eq!(res.merged_abi[2].state_mutability, Some(EvmContractStateMutability::View));
+    assert_eq!(res.merged_abi[4].name.as_deref(), Some("transfer"));
+}
+
+#[test]
+fn test_resolve_proxy_cycle_and_depth() {
+    let fetch = |address: &str| match address {
+        "0xA" => Ok(proxy_record("A", PROXY_ABI, "0xB")),
+        "0xB" => Ok(proxy_record("B", PROXY_ABI, "0xC")),
+        "0xC" => Ok(proxy_record("C", PROXY_ABI, "0xa")),
+        _ => panic!("unexpected address {}", address),
+    };
+
+    match Contracts::resolve_proxy_with("0xA", 10, fetch) {
+        Err(crate::prelude::EvmError::ErrorProxyResolution(msg)) => assert!(msg.contains("cycle")),
+        _ => panic!("cycle must be detected"),
+    }
+    match Contracts::resolve_proxy_with("0xA", 1, fetch) {
+        Err(crate::prelude::EvmError::ErrorProxyResolution(msg)) => assert!(msg.contains("more than 1")),
+        _ => panic!("depth limit must be enforced"),
+    }
+
+    // non-proxy contract resolves to itself
+    let res = Contracts::resolve_proxy_with("0xT", 0, |_| Ok(proxy_record("T", IMPLEMENTATION_ABI, ""))).unwrap();
+    assert_eq!(res.levels.len(), 1);
+    assert_eq!(res.merged_abi, res.levels[0].abi);
+}
+
+#[test]
+fn test_contract_creation_from_response() {
+    let json: EvmContractCreationResponse = serde_json::from_str(r#"{"status":"1","message":"OK","result":[
+        {"contractAddress":"0xb83c27805aaca5c7082eb45c868d955cf04c337f","contractCreator":"0x3c6b5ab2a1a4d1e4c5d24a5b7bd6c0c2b6e0a1b2","txHash":"0x0fb4bb3b5d2e1c7d7e0d1b5c4a3e2f1d0c9b8a7f6e5d4c3b2a1f0e9d8c7b6a5f"}
+    ]}"#).unwrap();
+    let creations = Contracts::contract_creation_from_response(json).unwrap();
+    assert_eq!(creations.len(), 1);
+    assert_eq!(creations[0].contract_address, "0xb83c27805aaca5c7082eb45c868d955cf04c337f");
+    assert_eq!(creations[0].contract_creator, "0x3c6b5ab2a1a4d1e4c5d24a5b7bd6c0c2b6e0a1b2");
+    assert_eq!(creations[0].tx_hash, "0x0fb4bb3b5d2e1c7d7e0d1b5c4a3e2f1d0c9b8a7f6e5d4c3b2a1f0e9d8c7b6a5f");
+
+    let json: EvmContractCreationResponse = serde_json::from_str(r#"{"status":"0","message":"No data found","result":null}"#).unwrap();
+    assert!(Contracts::contract_creation_from_response(json).unwrap().is_empty());
+
+    let json: EvmContractCreationResponse = serde_json::from_str(r#"{"status":"0","message":"NOTOK","result":"Invalid API Key"}"#).unwrap();
+    assert!(Contracts::contract_creation_from_response(json).is_err());
+}
+
+/// Create response of submitting, or checking verification.
+fn verification_response(status: &str, message: &str, result: &str) -> EvmContractVerificationResponse {
+    serde_json::from_value(serde_json::json!({ "status": status, "message": message, "result": result })).unwrap()
+}
+
+#[test]
+fn test_verification_status_from_response() {
+    let status = |s, m, r| Contracts::verification_status_from_response(verification_response(s, m, r));
+
+    assert_eq!(status("0", "NOTOK", "Pending in queue").unwrap(), EvmContractVerificationStatus::Pending);
+    assert_eq!(status("1", "OK", "Pass - Verified").unwrap(), EvmContractVerificationStatus::Pass);
+    assert_eq!(status("0", "NOTOK", "Already Verified").unwrap(), EvmContractVerificationStatus::Pass);
+    assert_eq!(status("0", "NOTOK", "Fail - Unable to verify").unwrap(), EvmContractVerificationStatus::Fail("Fail - Unable to verify".to_owned()));
+    assert!(status("0", "NOTOK", "Invalid API Key").is_err());
+
+    // proxy verification
+    assert_eq!(status("1", "OK", "The proxy's (0x5b0...) implementation contract is found at 0x1c5... and is successfully updated.").unwrap(), EvmContractVerificationStatus::Pass);
+    assert!(matches!(status("0", "NOTOK", "A corresponding implementation contract was unfortunately not detected for the proxy address.").unwrap(), EvmContractVerificationStatus::Fail(_)));
+
+    let guid = Contracts::verification_guid_from_response(verification_response("1", "OK", "ezq878u486pzijkvvmerl6a9mzwhv6sefgvqi5tkwceejc7tvn")).unwrap();
+    assert_eq!(guid, "ezq878u486pzijkvvmerl6a9mzwhv6sefgvqi5tkwceejc7tvn");
+    assert!(Contracts::verification_guid_from_response(verification_response("0", "NOTOK", "Contract source code already verified")).is_err());
+}
+
+#[test]
+fn test_parsed_compiler_metadata() {
+    let mut record = source_code_record("contract Vault {}");
+
+    let compiler = record.parsed_compiler_version().unwrap();
+    assert_eq!(compiler.kind, EvmCompilerKind::Solc);
+    assert_eq!(compiler.version, semver::Version::new(0, 8, 12));
+    assert_eq!(compiler.commit.as_deref(), Some("f00d7308"));
+    assert_eq!(compiler.to_string(), "v0.8.12+commit.f00d7308");
+    assert!(!compiler.is_prerelease());
+    // flag known buggy releases
+    assert!(semver::VersionReq::parse(">=0.8.0, <0.8.13").unwrap().matches(&compiler.version));
+
+    record.compiler_version = "v0.4.24-nightly.2018.05.16+commit.7f965c86".to_owned();
+    let nightly = record.parsed_compiler_version().unwrap();
+    assert!(nightly.is_prerelease());
+    assert!(nightly < compiler);
+
+    record.compiler_version = "vyper:0.3.7".to_owned();
+    let vyper = record.parsed_compiler_version().unwrap();
+    assert_eq!(vyper.kind, EvmCompilerKind::Vyper);
+    assert_eq!(vyper.version, semver::Version::new(0, 3, 7));
+    assert_eq!(vyper.commit, None);
+    assert!(EvmCompilerVersion::parse("latest").is_err());
+
+    assert_eq!(record.parsed_evm_version(), EvmVersion::Default);
+    record.evm_version = "tangerineWhistle".to_owned();
+    assert_eq!(record.parsed_evm_version(), EvmVersion::TangerineWhistle);
+    record.evm_version = "osaka".to_owned();
+    assert_eq!(record.parsed_evm_version(), EvmVersion::Other("osaka".to_owned()));
+
+    assert!(record.parsed_libraries().unwrap().is_empty());
+    record.library = "SafeMath:7e4f0b6a8c1e3b5d2a9c0f1e2d3c4b5a6978e8f1".to_owned();
+    assert_eq!(record.parsed_libraries().unwrap()["SafeMath"], "0x7e4f0b6a8c1e3b5d2a9c0f1e2d3c4b5a6978e8f1");
+    record.library = "SafeMath".to_owned();
+    assert!(record.parsed_libraries().is_err());
+
+    assert_eq!(record.parsed_license_type(), EvmContractLicense::Mit);
+    assert_eq!(EvmContractLicense::parse("GNU GPLv3").spdx_id(), Some("GPL-3.0"));
+    assert_eq!(EvmContractLicense::parse("GPL-3.0-or-later"), EvmContractLicense::GplV3);
+    assert_eq!(EvmContractLicense::parse("None").code(), Some(1));
+    assert_eq!(EvmContractLicense::parse("UNLICENSED"), EvmContractLicense::None);
+    assert_eq!(EvmContractLicense::parse("Unlicense"), EvmContractLicense::Unlicense);
+    assert_eq!(EvmContractLicense::parse("BSL 1.1").code(), Some(14));
+
+    record.swarm_source = "ipfs://QmSwzXwf1Ly8XKj3BRa8Jd5DvtDWaP7bjsNzTtYPwxpRn4".to_owned();
+    assert_eq!(record.parsed_swarm_source(), EvmSwarmSource::Ipfs("QmSwzXwf1Ly8XKj3BRa8Jd5DvtDWaP7bjsNzTtYPwxpRn4".to_owned()));
+    record.swarm_source = "bzzr://d62d5a0fa7cd9d2d3b1e2b1d0c2f4e3a5b6c7d8e9f0a1b2c3d4e5f6a7b8c9d0e".to_owned();
+    assert_eq!(record.parsed_swarm_source().hash(), Some("d62d5a0fa7cd9d2d3b1e2b1d0c2f4e3a5b6c7d8e9f0a1b2c3d4e5f6a7b8c9d0e"));
+    record.swarm_source = String::new();
+    assert_eq!(record.parsed_swarm_source(), EvmSwarmSource::None);
+    assert_eq!(EvmSwarmSource::parse("unknown").hash(), None);
+}
+
+/// Vyper's Standard JSON input with both source, and JSON interface.
+static VYPER_STANDARD_JSON_SOURCE: &str = r##"{{
+  "language": "Vyper",
+  "sources": {
+    "contracts/Vault.vy": { "content": "# @version 0.3.7\nimport interfaces.IERC20 as IERC20\n\n@external\ndef deposit(amount: uint256):\n    pass\n" }
+  },
+  "interfaces": {
+    "interfaces/IERC20.json": { "abi": [{"name":"totalSupply","type":"function","inputs":[],"outputs":[{"name":"","type":"uint256"}],"stateMutability":"view"}] },
+    "interfaces/IOracle.vy": { "content": "@external\ndef price() -> uint256:\n    pass\n" }
+  },
+  "settings": { "evmVersion": "paris", "optimize": "gas" }
+}}"##;
+
+#[test]
+fn test_split_verified_source_vyper() {
+    let mut record = source_code_record(VYPER_STANDARD_JSON_SOURCE);
+    record.compiler_version = "vyper:0.3.7".to_owned();
+    let verified = Contracts::split_verified_source(record).unwrap();
+
+    assert_eq!(verified.language, EvmContractLanguage::Vyper);
+    assert!(verified.is_standard_json);
+    assert_eq!(verified.sources.len(), 1);
+    assert_eq!(verified.sources[0].path, "contracts/Vault.vy");
+    assert_eq!(verified.interfaces.len(), 2);
+    assert_eq!(verified.interfaces[0].path, "interfaces/IERC20.json");
+    assert!(verified.interfaces[0].content.contains("totalSupply"));
+    assert_eq!(verified.interfaces[1].path, "interfaces/IOracle.vy");
+    let settings = verified.settings.as_ref().unwrap();
+    assert_eq!(settings.evm_version.as_deref(), Some("paris"));
+    assert_eq!(settings.other["optimize"], "gas");
+
+    // JSON interface goes back as `abi` when reproducing the input
+    let input = crate::export::to_standard_json_input(&verified).unwrap();
+    let value = serde_json::to_value(&input).unwrap();
+    assert_eq!(value["language"], "Vyper");
+    assert_eq!(value["interfaces"]["interfaces/IERC20.json"]["abi"][0]["name"], "totalSupply");
+    assert!(value["interfaces"]["interfaces/IOracle.vy"]["content"].is_string());
+
+    // single file is detected from compiler version
+    let mut record = source_code_record("# @version 0.2.11\n@external\ndef f():\n    pass\n");
+    record.compiler_version = "vyper:0.2.11".to_owned();
+    let verified = Contracts::split_verified_source(record).unwrap();
+    assert_eq!(verified.language, EvmContractLanguage::Vyper);
+    assert_eq!(verified.sources[0].path, "Vault.vy");
+
+    let verified = Contracts::split_verified_source(source_code_record("contract Vault {}")).unwrap();
+    assert_eq!(verified.language, EvmContractLanguage::Solidity);
+}
+
+#[test]
+fn test_split_source_code_records_vyper() {
+    let mut record = source_code_record(VYPER_STANDARD_JSON_SOURCE);
+    record.compiler_version = "vyper:0.3.7".to_owned();
+    let (contracts, is_json) = Contracts::split_source_code_records(vec![record]).unwrap();
+
+    assert!(is_json);
+    // combined record, source file, then interface files
+    assert_eq!(contracts.len(), 4);
+    assert_eq!(contracts[1].contract_name, "contracts/Vault.vy");
+    assert_eq!(contracts[2].contract_name, "interfaces/IERC20.json");
+    assert!(contracts[2].source_code.contains("totalSupply"));
+    assert_eq!(contracts[3].contract_name, "interfaces/IOracle.vy");
+    assert!(contracts.iter().all(|c| c.language == EvmContractLanguage::Vyper));
+
+    let (contracts, _) = Contracts::split_source_code_records(vec![source_code_record(STANDARD_JSON_SOURCE)]).unwrap();
+    assert!(contracts.iter().all(|c| c.language == EvmContractLanguage::Solidity));
+}
@@ -0,0 +1,119 @@
+/**
+ * Tests for comparing verified source code of two contracts which operate on
+ * already fetched data, thus no API key, or network access is needed.
+ */
+#[cfg(test)]
+use crate::types::evm_types::*;
+use crate::api::contracts::Contracts;
+use crate::diff;
+use crate::tests::contracts_tests::{source_code_record, STANDARD_JSON_SOURCE};
+
+static OLD_ABI: &str = r#"[
+    {"inputs":[{"name":"owner","type":"address"}],"stateMutability":"nonpayable","type":"constructor"},
+    {"inputs":[],"name":"totalAssets","outputs":[{"name":"","type":"uint256"}],"stateMutability":"view","type":"function"},
+    {"inputs":[{"name":"amount","type":"uint256"}],"name":"deposit","outputs":[],"stateMutability":"nonpayable","type":"function"},
+    {"inputs":[],"name":"pause","outputs":[],"stateMutability":"nonpayable","type":"function"},
+    {"anonymous":false,"inputs":[{"indexed":true,"name":"user","type":"address"}],"name":"Deposited","type":"event"}
+]"#;
+
+static NEW_ABI: &str = r#"[
+    {"inputs":[{"name":"owner","type":"address"}],"stateMutability":"nonpayable","type":"constructor"},
+    {"inputs":[],"name":"totalAssets","outputs":[{"name":"","type":"uint128"}],"stateMutability":"view","type":"function"},
+    {"inputs":[{"name":"amount","type":"uint256"}],"name":"deposit","outputs":[],"stateMutability":"payable","type":"function"},
+    {"anonymous":false,"inputs":[{"indexed":true,"name":"user","type":"address"}],"name":"Deposited","type":"event"},
+    {"anonymous":false,"inputs":[{"indexed":false,"name":"amount","type":"uint256"}],"name":"Withdrawn","type":"event"}
+]"#;
+
+#[test]
+fn test_diff_abi() {
+    let old = Contracts::parse_abi(OLD_ABI).unwrap();
+    let new = Contracts::parse_abi(NEW_ABI).unwrap();
+    let changes = diff::diff_abi(&old, &new);
+
+    assert_eq!(changes.len(), 4);
+    assert_eq!(changes[0], EvmAbiItemChange {
+        item_type: EvmContractABIItemKind::Function,
+        signature: "totalAssets()".to_owned(),
+        kind: EvmAbiChangeKind::OutputsChanged,
+    });
+    assert_eq!(changes[1].signature, "deposit(uint256)");
+    assert_eq!(changes[1].kind, EvmAbiChangeKind::MutabilityChanged(Some(EvmContractStateMutability::NonPayable), Some(EvmContractStateMutability::Payable)));
+    assert_eq!(changes[2].signature, "pause()");
+    assert_eq!(changes[2].kind, EvmAbiChangeKind::Removed);
+    assert_eq!(changes[3].item_type, EvmContractABIItemKind::Event);
+    assert_eq!(changes[3].signature, "Withdrawn(uint256)");
+    assert_eq!(changes[3].kind, EvmAbiChangeKind::Added);
+
+    assert!(diff::diff_abi(&old, &old).is_empty());
+}
+
+#[test]
+fn test_diff_abi_legacy_mutability() {
+    let old = Contracts::parse_abi(r#"[
+        {"constant":true,"inputs":[],"name":"totalAssets","outputs":[{"name":"","type":"uint256"}],"payable":false,"type":"function"},
+        {"constant":false,"inputs":[{"name":"amount","type":"uint256"}],"name":"deposit","outputs":[],"payable":false,"type":"function"}
+    ]"#).unwrap();
+    let new = Contracts::parse_abi(r#"[
+        {"inputs":[],"name":"totalAssets","outputs":[{"name":"","type":"uint256"}],"stateMutability":"view","type":"function"},
+        {"inputs":[{"name":"amount","type":"uint256"}],"name":"deposit","outputs":[],"stateMutability":"payable","type":"function"}
+    ]"#).unwrap();
+    let changes = diff::diff_abi(&old, &new);
+
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].signature, "deposit(uint256)");
+    assert_eq!(changes[0].kind, EvmAbiChangeKind::MutabilityChanged(Some(EvmContractStateMutability::NonPayable), Some(EvmContractStateMutability::Payable)));
+}
+
+#[test]
+fn test_diff_verified_sources() {
+    let old = Contracts::split_verified_source(source_code_record(STANDARD_JSON_SOURCE)).unwrap();
+
+    // upgrade modifies one file, adds another, and changes optimizer runs
+    let mut new = old.clone();
+    new.sources[0].content = new.sources[0].content.replace("struct Info { uint256 a; }", "struct Info { uint256 a; uint256 b; }");
+    new.sources.push(EvmContractSourceFile { path: "contracts/Lib.sol".to_owned(), content: "library Lib {}\n".to_owned() });
+    new.settings.as_mut().unwrap().optimizer.as_mut().unwrap().runs = Some(200);
+    new.contract.abi = NEW_ABI.to_owned();
+
+    let res = diff::diff_verified_sources(&old, &new).unwrap();
+    assert!(!res.is_identical());
+
+    assert_eq!(res.files.len(), 2);
+    assert_eq!(res.files[0].kind, EvmSourceFileChangeKind::Modified);
+    assert_eq!(res.files[0].old_path.as_deref(), Some("contracts/Vault.sol"));
+    assert!(res.files[0].unified_diff.starts_with("--- a/contracts/Vault.sol\n+++ b/contracts/Vault.sol\n@@"));
+    assert!(res.files[0].unified_diff.contains("\n-    struct Info { uint256 a; }\n+    struct Info { uint256 a; uint256 b; }\n"));
+    assert_eq!(res.files[1].kind, EvmSourceFileChangeKind::Added);
+    assert_eq!(res.files[1].old_path, None);
+    assert!(res.files[1].unified_diff.starts_with("--- /dev/null\n+++ b/contracts/Lib.sol\n"));
+
+    assert_eq!(res.settings, vec![EvmSettingDiff {
+        name: "optimizer.runs".to_owned(),
+        old: Some("999999".to_owned()),
+        new: Some("200".to_owned()),
+    }]);
+
+    // old ABI is empty
+    assert_eq!(res.abi.len(), 5);
+    assert!(res.abi.iter().all(|c| c.kind == EvmAbiChangeKind::Added));
+
+    assert!(diff::diff_verified_sources(&old, &old).unwrap().is_identical());
+}
+
+#[test]
+fn test_diff_single_file_renamed() {
+    let old = Contracts::split_verified_source(source_code_record("contract Vault {\n    uint256 a;\n}\n")).unwrap();
+    let mut record = source_code_record("contract VaultV2 {\n    uint256 a;\n}\n");
+    record.contract_name = "VaultV2".to_owned();
+    record.compiler_version = "v0.8.19+commit.7dd6d404".to_owned();
+    record.optimization_used = false;
+    let new = Contracts::split_verified_source(record).unwrap();
+
+    let res = diff::diff_verified_sources(&old, &new).unwrap();
+    assert_eq!(res.files.len(), 1);
+    assert_eq!(res.files[0].kind, EvmSourceFileChangeKind::Modified);
+    assert_eq!(res.files[0].new_path.as_deref(), Some("VaultV2.sol"));
+
+    let names: Vec<&str> = res.settings.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, vec!["compilerVersion", "optimizer.enabled"]);
+}
@@ -116,7 +116,7 @@ serial_test! {
         let res = evmscan::contracts().get_abi(&ctx, &ADDRESS1, false);
         assert!(res.is_ok());
         assert!(res.unwrap().len() == 12500);   // exact number of character
-                                                // from cleaned '\' char
+                                                // of ABI as verified
     }
 }
 
@@ -0,0 +1,82 @@
+/**
+ * Tests for exporting verified contract's source code to file system which
+ * operate on already fetched data, thus no API key, or network access is needed.
+ */
+#[cfg(test)]
+use crate::types::evm_types::*;
+use crate::api::contracts::Contracts;
+use crate::export;
+use crate::tests::contracts_tests::{source_code_record, STANDARD_JSON_SOURCE};
+
+/// Create empty temporary directory unique to the test.
+fn temp_dir(name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("evmscan-export-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test]
+fn test_export_foundry_project_standard_json() {
+    let verified = Contracts::split_verified_source(source_code_record(STANDARD_JSON_SOURCE)).unwrap();
+    let dir = temp_dir("standard-json");
+    let files = export::export_foundry_project(&verified, &dir).unwrap();
+    assert_eq!(files.len(), 5);
+
+    // original import paths are preserved under src
+    let content = std::fs::read_to_string(dir.join("src/@openzeppelin/contracts/access/Ownable.sol")).unwrap();
+    assert_eq!(content, verified.sources[1].content);
+    assert!(dir.join("src/contracts/Vault.sol").is_file());
+
+    let remappings = std::fs::read_to_string(dir.join("remappings.txt")).unwrap();
+    assert_eq!(remappings, "@openzeppelin/=src/@openzeppelin/\ncontracts/=src/contracts/\n");
+
+    let toml = std::fs::read_to_string(dir.join("foundry.toml")).unwrap();
+    assert!(toml.contains("solc_version = \"0.8.12\"\n"));
+    assert!(toml.contains("optimizer = true\noptimizer_runs = 999999\n"));
+    assert!(toml.contains("evm_version = \"london\"\n"));
+    assert!(toml.contains("via_ir = true\n"));
+    assert!(toml.contains("libraries = [\"src/contracts/Lib.sol:Lib:0x00000000000000000000000000000000000000aa\"]\n"));
+
+    // Standard JSON input is kept as submitted, and can be parsed back
+    let text = std::fs::read_to_string(dir.join("standard_json_input.json")).unwrap();
+    let input = Contracts::parse_source_code(&text).unwrap().unwrap();
+    assert_eq!(input.sources, verified.sources);
+    assert_eq!(input.settings, verified.settings);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_to_standard_json_input_single_file() {
+    let mut record = source_code_record("contract Vault {}");
+    record.evm_version = "istanbul".to_owned();
+    record.library = "SafeMath:7e4f0b6a8c1e3b5d2a9c0f1e2d3c4b5a6978e8f1;Oracle:0x00000000000000000000000000000000000000bb".to_owned();
+    let verified = Contracts::split_verified_source(record).unwrap();
+
+    let input = export::to_standard_json_input(&verified).unwrap();
+    assert_eq!(input.sources, verified.sources);
+    let settings = input.settings.unwrap();
+    let optimizer = settings.optimizer.unwrap();
+    assert_eq!(optimizer.enabled, Some(true));
+    assert_eq!(optimizer.runs, Some(200));
+    assert_eq!(settings.evm_version.as_deref(), Some("istanbul"));
+    assert_eq!(settings.libraries["Vault.sol"]["SafeMath"], "0x7e4f0b6a8c1e3b5d2a9c0f1e2d3c4b5a6978e8f1");
+    assert_eq!(settings.libraries["Vault.sol"]["Oracle"], "0x00000000000000000000000000000000000000bb");
+    assert!(settings.other.contains_key("outputSelection"));
+
+    // "Default" EVM version is left to the compiler
+    let verified = Contracts::split_verified_source(source_code_record("contract Vault {}")).unwrap();
+    let settings = export::to_standard_json_input(&verified).unwrap().settings.unwrap();
+    assert_eq!(settings.evm_version, None);
+    assert_eq!(export::foundry_remappings(&verified).unwrap(), vec!["Vault.sol=src/Vault.sol".to_owned()]);
+}
+
+#[test]
+fn test_export_rejects_path_outside_directory() {
+    let mut verified = Contracts::split_verified_source(source_code_record("contract Vault {}")).unwrap();
+    verified.sources.push(EvmContractSourceFile { path: "../../etc/evil.sol".to_owned(), content: String::new() });
+
+    let dir = temp_dir("outside");
+    assert!(export::export_source_files(&verified, &dir).is_err());
+    let _ = std::fs::remove_dir_all(&dir);
+}
@@ -0,0 +1,50 @@
+/**
+ * Tests for Gas Tracker related functionality which operate on already fetched
+ * API responses, thus no API key, or network access is needed.
+ */
+#[cfg(test)]
+use crate::prelude::*;
+use crate::types::evm_types::*;
+use crate::api::gastracker::GasTracker;
+
+#[test]
+fn test_gas_oracle_from_response_per_chain() {
+    let text = r#"{"status":"1","message":"OK","result":{"LastBlock":"13053741","SafeGasPrice":"20","ProposeGasPrice":"22","FastGasPrice":"24","suggestBaseFee":"19.230609716","gasUsedRatio":"0.370119078777807,0.8954731,0.550911766666667"}}"#;
+    let oracle = GasTracker::gas_oracle_from_response(serde_json::from_str::<EvmGasOracleResponse<EvmGasOracle_Ethereum>>(text).unwrap()).unwrap();
+    assert_eq!(oracle.last_block, 13053741);
+    assert_eq!(oracle.safe_gas_price, 20.0);
+    assert_eq!(oracle.propose_gas_price, 22.0);
+    assert_eq!(oracle.fast_gas_price, 24.0);
+    assert_eq!(oracle.suggest_base_fee, Some(19.230609716));
+    assert_eq!(oracle.gas_used_ratio, vec![0.370119078777807, 0.8954731, 0.550911766666667]);
+    assert_eq!(oracle.usd_price, None);
+
+    let text = r#"{"status":"1","message":"OK","result":{"LastBlock":"24151210","SafeGasPrice":"3","ProposeGasPrice":"3","FastGasPrice":"3.5","UsdPrice":"312.45"}}"#;
+    let oracle = GasTracker::gas_oracle_from_response(serde_json::from_str::<EvmGasOracleResponse<EvmGasOracle_BSC>>(text).unwrap()).unwrap();
+    assert_eq!(oracle.last_block, 24151210);
+    assert_eq!(oracle.fast_gas_price, 3.5);
+    assert_eq!(oracle.suggest_base_fee, None);
+    assert!(oracle.gas_used_ratio.is_empty());
+    assert_eq!(oracle.usd_price, Some(312.45));
+
+    let text = r#"{"status":"1","message":"OK","result":{"LastBlock":"38261578","SafeGasPrice":"30.1","ProposeGasPrice":"31.2","FastGasPrice":"32.3","suggestBaseFee":"0.000000015","gasUsedRatio":"0.5,0.6","UsdPrice":"0.85"}}"#;
+    let oracle = GasTracker::gas_oracle_from_response(serde_json::from_str::<EvmGasOracleResponse<EvmGasOracle_Polygon>>(text).unwrap()).unwrap();
+    assert_eq!(oracle.safe_gas_price, 30.1);
+    assert_eq!(oracle.suggest_base_fee, Some(0.000000015));
+    assert_eq!(oracle.gas_used_ratio, vec![0.5, 0.6]);
+    assert_eq!(oracle.usd_price, Some(0.85));
+
+    let text = r#"{"status":"0","message":"NOTOK","result":"Invalid API Key"}"#;
+    match GasTracker::gas_oracle_from_response(serde_json::from_str::<EvmGasOracleResponse<EvmGasOracle_BSC>>(text).unwrap()) {
+        Err(EvmError::ErrorApiResponse(msg)) => assert!(msg.contains("Invalid API Key")),
+        res => panic!("unexpected result {:?}", res),
+    }
+}
+
+#[test]
+fn test_confirmation_time_from_response() {
+    let parse = |text: &str| GasTracker::confirmation_time_from_response(serde_json::from_str::<EvmGasEstimateResponse>(text).unwrap());
+
+    assert_eq!(parse(r#"{"status":"1","message":"OK","result":"9633"}"#).unwrap(), 9633);
+    assert!(parse(r#"{"status":"0","message":"NOTOK","result":"Error! Missing Or invalid Module name"}"#).is_err());
+}
@@ -0,0 +1,127 @@
+/**
+ * Tests for Logs related functionality which operate on already fetched API
+ * responses, or simulated pages, thus no API key, or network access is needed.
+ */
+#[cfg(test)]
+use crate::prelude::*;
+use crate::types::evm_types::*;
+use crate::api::logs::Logs;
+
+static TRANSFER_TOPIC: &str = "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef";
+
+/// Create a log at `block_number` with `log_index`.
+fn log_at(block_number: u64, log_index: u64) -> EvmLog {
+    EvmLog {
+        address: "0x00000000000000000000000000000000000000cc".to_owned(),
+        topics: vec![TRANSFER_TOPIC.to_owned()],
+        data: "0x".to_owned(),
+        block_number,
+        block_hash: None,
+        timestamp: 0,
+        gas_price: U256::zero(),
+        gas_used: U256::zero(),
+        log_index,
+        transaction_hash: format!("0x{:064x}", block_number),
+        transaction_index: 0,
+    }
+}
+
+/// Simulate API's paging of `logs` with page size of 1000.
+fn page_of(logs: &[EvmLog], from_block: u64, page_number: usize) -> Vec<EvmLog> {
+    logs.iter()
+        .filter(|log| log.block_number >= from_block)
+        .skip((page_number - 1) * 1000)
+        .take(1000)
+        .cloned()
+        .collect()
+}
+
+#[test]
+fn test_logs_from_response() {
+    let text = r#"{"status":"1","message":"OK","result":[{
+        "address":"0xbd3531da5cf5857e7cfaa92426877b022e612cf8",
+        "topics":[
+            "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef",
+            "0x0000000000000000000000000000000000000000000000000000000000000000",
+            "0x000000000000000000000000c8a5592031f93debea5d9e67a396944ee01bb2ca"
+        ],
+        "data":"0x",
+        "blockNumber":"0xc48174",
+        "blockHash":"0x4eb0a7e0c1f4a1e1b1f2d0cb0b3a2f6d0f0a8a9b5b7d8c0e1f2a3b4c5d6e7f80",
+        "timeStamp":"0x60f9ce56",
+        "gasPrice":"0x2e90edd000",
+        "gasUsed":"0x247205",
+        "logIndex":"0x",
+        "transactionHash":"0x4ffd22d986913d33927a392fe4319bcd2b62f3afe1c15a2c59f77fc2cc4c20a9",
+        "transactionIndex":"0x1a"
+    }]}"#;
+    let logs = Logs::logs_from_response(serde_json::from_str::<EvmLogsResponse>(text).unwrap()).unwrap();
+    assert_eq!(logs.len(), 1);
+    let log = &logs[0];
+    assert_eq!(log.topics.len(), 3);
+    assert_eq!(log.topics[0], TRANSFER_TOPIC);
+    assert_eq!(log.block_number, 0xc48174);
+    assert_eq!(log.timestamp, 0x60f9ce56);
+    assert_eq!(log.gas_price, U256::from(0x2e90edd000u64));
+    assert_eq!(log.gas_used, U256::from(0x247205));
+    // `0x` alone means zero
+    assert_eq!(log.log_index, 0);
+    assert_eq!(log.transaction_index, 0x1a);
+
+    let text = r#"{"status":"0","message":"No records found","result":[]}"#;
+    assert!(Logs::logs_from_response(serde_json::from_str::<EvmLogsResponse>(text).unwrap()).unwrap().is_empty());
+
+    let text = r#"{"status":"0","message":"NOTOK","result":"Invalid API Key"}"#;
+    assert!(Logs::logs_from_response(serde_json::from_str::<EvmLogsResponse>(text).unwrap()).is_err());
+}
+
+#[test]
+fn test_filter_params() {
+    let mut filter = EvmLogFilter::create(None, 100, None);
+    assert!(Logs::filter_params(&filter).is_err());
+
+    filter.address = Some("0xbd3531da5cf5857e7cfaa92426877b022e612cf8".to_owned());
+    assert_eq!(Logs::filter_params(&filter).unwrap(), "&toBlock=latest&address=0xbd3531da5cf5857e7cfaa92426877b022e612cf8");
+
+    let mut filter = EvmLogFilter::create(None, 100, Some(200));
+    filter.topics[0] = Some(TRANSFER_TOPIC.to_owned());
+    filter.topics[2] = Some("0x000000000000000000000000c8a5592031f93debea5d9e67a396944ee01bb2ca".to_owned());
+    filter.topic_operators.push((0, 2, EvmLogTopicOperator::Or));
+    assert_eq!(
+        Logs::filter_params(&filter).unwrap(),
+        format!("&toBlock=200&topic0={}&topic2=0x000000000000000000000000c8a5592031f93debea5d9e67a396944ee01bb2ca&topic0_2_opr=or", TRANSFER_TOPIC));
+
+    // operator on unset topic
+    filter.topic_operators.push((0, 1, EvmLogTopicOperator::And));
+    assert!(Logs::filter_params(&filter).is_err());
+
+    let filter = EvmLogFilter::create(Some("0xbd3531da5cf5857e7cfaa92426877b022e612cf8"), 200, Some(100));
+    assert!(Logs::filter_params(&filter).is_err());
+}
+
+#[test]
+fn test_paginate_beyond_window() {
+    // 25,000 logs with 30 logs per block, so windows end in the middle of a block
+    let logs: Vec<EvmLog> = (0..25_000u64).map(|i| log_at(1000 + i / 30, i % 30)).collect();
+
+    let mut requests = 0;
+    let res = Logs::paginate(1000, |from_block, page_number| {
+        requests += 1;
+        Ok(page_of(&logs, from_block, page_number))
+    }).unwrap();
+    assert_eq!(res, logs);
+    assert!(requests > 25);
+
+    // fewer logs than a page
+    let res = Logs::paginate(1000, |from_block, page_number| Ok(page_of(&logs[..10], from_block, page_number))).unwrap();
+    assert_eq!(res.len(), 10);
+}
+
+#[test]
+fn test_paginate_single_block_over_window() {
+    let logs: Vec<EvmLog> = (0..12_000u64).map(|i| log_at(1000, i)).collect();
+    match Logs::paginate(1000, |from_block, page_number| Ok(page_of(&logs, from_block, page_number))) {
+        Err(EvmError::ErrorApiResponse(msg)) => assert!(msg.contains("block 1000")),
+        res => panic!("expected error, got {:?}", res.map(|logs| logs.len())),
+    }
+}
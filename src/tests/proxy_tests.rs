@@ -0,0 +1,249 @@
+/**
+ * Tests for Proxy related functionality which operate on already fetched
+ * JSON-RPC responses, thus no API key, or network access is needed.
+ */
+#[cfg(test)]
+use crate::prelude::*;
+use crate::types::evm_types::*;
+use crate::api::proxy::Proxy;
+use crate::deserialize::{parse_hex_quantity_u64, parse_hex_quantity_U256};
+
+/// Parse API response in JSON text then get its result as `T`.
+fn result_from_text<T: serde::de::DeserializeOwned>(text: &str) -> Result<T, EvmError> {
+    Proxy::result_from_response::<T>(serde_json::from_str::<EvmProxyResponse>(text).unwrap())
+}
+
+/// Block with a single EIP-1559 transaction in full form.
+static BLOCK_WITH_TRANSACTIONS: &str = r#"{"jsonrpc":"2.0","id":1,"result":{
+    "baseFeePerGas":"0x3b9aca00",
+    "difficulty":"0x0",
+    "extraData":"0x",
+    "gasLimit":"0x1c9c380",
+    "gasUsed":"0x5208",
+    "hash":"0x7c5f8d2a1b3c4d5e6f708192a3b4c5d6e7f8091a2b3c4d5e6f708192a3b4c5d6",
+    "logsBloom":"0x00",
+    "miner":"0x0000000000000000000000000000000000000001",
+    "mixHash":"0x1111111111111111111111111111111111111111111111111111111111111111",
+    "nonce":"0x0000000000000000",
+    "number":"0x10d4f",
+    "parentHash":"0x2222222222222222222222222222222222222222222222222222222222222222",
+    "receiptsRoot":"0x3333333333333333333333333333333333333333333333333333333333333333",
+    "sha3Uncles":"0x4444444444444444444444444444444444444444444444444444444444444444",
+    "size":"0x220",
+    "stateRoot":"0x5555555555555555555555555555555555555555555555555555555555555555",
+    "timestamp":"0x5ea0e4f2",
+    "totalDifficulty":"0xc70d815d562d3cfa955",
+    "transactions":[{
+        "blockHash":"0x7c5f8d2a1b3c4d5e6f708192a3b4c5d6e7f8091a2b3c4d5e6f708192a3b4c5d6",
+        "blockNumber":"0x10d4f",
+        "from":"0x00000000000000000000000000000000000000aa",
+        "gas":"0x5208",
+        "gasPrice":"0x3b9aca01",
+        "maxFeePerGas":"0x77359400",
+        "maxPriorityFeePerGas":"0x1",
+        "hash":"0x6666666666666666666666666666666666666666666666666666666666666666",
+        "input":"0x",
+        "nonce":"0x2a",
+        "to":"0x00000000000000000000000000000000000000bb",
+        "transactionIndex":"0x0",
+        "value":"0xde0b6b3a7640000",
+        "type":"0x2",
+        "accessList":[],
+        "chainId":"0x1",
+        "v":"0x1",
+        "r":"0x7777",
+        "s":"0x8888"
+    }],
+    "transactionsRoot":"0x9999999999999999999999999999999999999999999999999999999999999999",
+    "uncles":[]
+}}"#;
+
+#[test]
+fn test_hex_quantity() {
+    assert_eq!(parse_hex_quantity_u64("0x10d4f").unwrap(), 0x10d4f);
+    assert_eq!(parse_hex_quantity_u64("0x").unwrap(), 0);
+    assert_eq!(parse_hex_quantity_u64("0x0").unwrap(), 0);
+    assert!(parse_hex_quantity_u64("10d4f").is_err());
+    assert!(parse_hex_quantity_u64("0x10000000000000000").is_err());
+    assert_eq!(parse_hex_quantity_U256("0xde0b6b3a7640000").unwrap(), U256::exp10(18));
+    assert!(parse_hex_quantity_U256("0xzz").is_err());
+
+    assert_eq!(EvmBlockParameter::Number(68943).as_tag(), "0x10d4f");
+    assert_eq!(EvmBlockParameter::Latest.as_tag(), "latest");
+}
+
+#[test]
+fn test_block_with_transactions() {
+    let block = result_from_text::<Option<EvmRpcBlock<EvmRpcTransaction>>>(BLOCK_WITH_TRANSACTIONS).unwrap().unwrap();
+    assert_eq!(block.number, Some(0x10d4f));
+    assert_eq!(block.timestamp, 0x5ea0e4f2);
+    assert_eq!(block.base_fee_per_gas, Some(U256::from(1_000_000_000u64)));
+    assert_eq!(block.gas_used, U256::from(21000));
+    assert_eq!(block.size, 0x220);
+
+    let tx = &block.transactions[0];
+    assert_eq!(tx.nonce, 42);
+    assert_eq!(tx.value, U256::exp10(18));
+    assert_eq!(tx.r#type, Some(2));
+    assert_eq!(tx.max_priority_fee_per_gas, Some(U256::one()));
+    assert_eq!(tx.to.as_deref(), Some("0x00000000000000000000000000000000000000bb"));
+    assert_eq!(tx.chain_id, Some(1));
+}
+
+#[test]
+fn test_block_with_hashes_and_pending() {
+    let text = BLOCK_WITH_TRANSACTIONS.replace(
+        &BLOCK_WITH_TRANSACTIONS[BLOCK_WITH_TRANSACTIONS.find("\"transactions\":[").unwrap()..BLOCK_WITH_TRANSACTIONS.find("\"transactionsRoot\"").unwrap()],
+        "\"transactions\":[\"0x6666666666666666666666666666666666666666666666666666666666666666\"],\n    ");
+    // pending block has no hash, number, nor nonce
+    let text = text
+        .replace("\"hash\":\"0x7c5f8d2a1b3c4d5e6f708192a3b4c5d6e7f8091a2b3c4d5e6f708192a3b4c5d6\"", "\"hash\":null")
+        .replace("\"number\":\"0x10d4f\"", "\"number\":null");
+    let block = result_from_text::<Option<EvmRpcBlock<String>>>(&text).unwrap().unwrap();
+    assert_eq!(block.transactions, vec!["0x6666666666666666666666666666666666666666666666666666666666666666".to_owned()]);
+    assert_eq!(block.hash, None);
+    assert_eq!(block.number, None);
+}
+
+#[test]
+fn test_result_from_response_errors() {
+    // block not found
+    assert!(result_from_text::<Option<EvmRpcBlock<String>>>(r#"{"jsonrpc":"2.0","id":1,"result":null}"#).unwrap().is_none());
+
+    assert_eq!(result_from_text::<String>(r#"{"jsonrpc":"2.0","id":83,"result":"0x10d4f"}"#).unwrap(), "0x10d4f");
+
+    match result_from_text::<String>(r#"{"jsonrpc":"2.0","id":1,"error":{"code":-32602,"message":"invalid argument 0: hex string without 0x prefix"}}"#) {
+        Err(EvmError::ErrorApiResponse(msg)) => assert!(msg.contains("-32602")),
+        _ => panic!("JSON-RPC error must be returned as error"),
+    }
+    match result_from_text::<String>(r#"{"status":"0","message":"NOTOK","result":"Invalid API Key"}"#) {
+        Err(EvmError::ErrorApiResponse(msg)) => assert!(msg.contains("Invalid API Key")),
+        _ => panic!("API's own error must be returned as error"),
+    }
+}
+
+/// Receipt of EIP-1559 transaction calling ERC-20's `transfer()` which emits
+/// a single `Transfer` event.
+static TRANSACTION_RECEIPT: &str = r#"{"jsonrpc":"2.0","id":1,"result":{
+    "blockHash":"0x7c5f8d2a1b3c4d5e6f708192a3b4c5d6e7f8091a2b3c4d5e6f708192a3b4c5d6",
+    "blockNumber":"0x10d4f",
+    "contractAddress":null,
+    "cumulativeGasUsed":"0x1a2b3",
+    "effectiveGasPrice":"0x3b9aca01",
+    "from":"0x00000000000000000000000000000000000000aa",
+    "gasUsed":"0xc350",
+    "logs":[{
+        "address":"0x00000000000000000000000000000000000000cc",
+        "topics":[
+            "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef",
+            "0x00000000000000000000000000000000000000000000000000000000000000aa",
+            "0x00000000000000000000000000000000000000000000000000000000000000bb"
+        ],
+        "data":"0x0000000000000000000000000000000000000000000000000de0b6b3a7640000",
+        "blockNumber":"0x10d4f",
+        "transactionHash":"0x6666666666666666666666666666666666666666666666666666666666666666",
+        "transactionIndex":"0x3",
+        "blockHash":"0x7c5f8d2a1b3c4d5e6f708192a3b4c5d6e7f8091a2b3c4d5e6f708192a3b4c5d6",
+        "logIndex":"0x7",
+        "removed":false
+    }],
+    "logsBloom":"0x00",
+    "status":"0x1",
+    "to":"0x00000000000000000000000000000000000000cc",
+    "transactionHash":"0x6666666666666666666666666666666666666666666666666666666666666666",
+    "transactionIndex":"0x3",
+    "type":"0x2"
+}}"#;
+
+#[test]
+fn test_transaction_receipt() {
+    let receipt = result_from_text::<Option<EvmRpcTransactionReceipt>>(TRANSACTION_RECEIPT).unwrap().unwrap();
+    assert_eq!(receipt.block_number, 0x10d4f);
+    assert_eq!(receipt.contract_address, None);
+    assert_eq!(receipt.is_success(), Some(true));
+    assert_eq!(receipt.r#type, Some(2));
+    assert_eq!(receipt.gas_used, U256::from(50000));
+    assert_eq!(receipt.fee(), Some(U256::from(50000u64 * 1_000_000_001u64)));
+
+    assert_eq!(receipt.logs.len(), 1);
+    let log = &receipt.logs[0];
+    assert_eq!(log.topics.len(), 3);
+    assert_eq!(log.log_index, Some(7));
+    assert_eq!(log.transaction_index, Some(3));
+    assert!(!log.removed);
+
+    // pre-Byzantium receipt has `root` instead of `status`
+    let text = TRANSACTION_RECEIPT.replace("\"status\":\"0x1\"", "\"root\":\"0xabcd\"").replace("\"effectiveGasPrice\":\"0x3b9aca01\",", "");
+    let receipt = result_from_text::<Option<EvmRpcTransactionReceipt>>(&text).unwrap().unwrap();
+    assert_eq!(receipt.is_success(), None);
+    assert_eq!(receipt.root.as_deref(), Some("0xabcd"));
+    assert_eq!(receipt.fee(), None);
+
+    // pending, or unknown transaction
+    assert!(result_from_text::<Option<EvmRpcTransactionReceipt>>(r#"{"jsonrpc":"2.0","id":1,"result":null}"#).unwrap().is_none());
+}
+
+#[test]
+fn test_code_and_storage() {
+    // EOA has no code
+    let code = result_from_text::<String>(r#"{"jsonrpc":"2.0","id":1,"result":"0x"}"#).unwrap();
+    assert!(crate::abi::decode::decode_hex(&code).unwrap().is_empty());
+    let code = result_from_text::<String>(r#"{"jsonrpc":"2.0","id":1,"result":"0x6080604052"}"#).unwrap();
+    assert_eq!(crate::abi::decode::decode_hex(&code).unwrap(), vec![0x60, 0x80, 0x60, 0x40, 0x52]);
+    assert!(!Proxy::is_contract_code(&[]));
+    assert!(Proxy::is_contract_code(&[0x60, 0x80, 0x60, 0x40, 0x52]));
+    // EOA delegated by EIP-7702
+    let delegation = crate::abi::decode::decode_hex("0xef010063c0c19a282a1b52b07dd5a65b58948a07dae32b").unwrap();
+    assert!(!Proxy::is_contract_code(&delegation));
+    assert!(Proxy::is_contract_code(&delegation[..22]));
+
+    let full = "0x000000000000000000000000bb4cdb9cbd36b01bd1cbaebf2de08d9173bc095c";
+    let value = Proxy::storage_value_from_hex(full).unwrap();
+    assert_eq!(format!("{:#x}", value), full);
+    // trimmed leading zeros are padded back
+    assert_eq!(Proxy::storage_value_from_hex("0x12").unwrap(), H256::from_low_u64_be(0x12));
+    assert_eq!(Proxy::storage_value_from_hex("0x").unwrap(), H256::zero());
+    assert!(Proxy::storage_value_from_hex(&format!("{}00", full)).is_err());
+
+    assert_eq!(parse_hex_quantity_u64(&result_from_text::<String>(r#"{"jsonrpc":"2.0","id":1,"result":"0x1f"}"#).unwrap()).unwrap(), 31);
+}
+
+#[test]
+fn test_send_raw_transaction_response() {
+    let hash = "0x6666666666666666666666666666666666666666666666666666666666666666";
+    let json = serde_json::from_str::<EvmProxyResponse>(&format!(r#"{{"jsonrpc":"2.0","id":1,"result":"{}"}}"#, hash)).unwrap();
+    assert_eq!(Proxy::transaction_hash_from_response(json).unwrap(), hash);
+
+    let cases = [
+        ("nonce too low", TransactionRejection::NonceTooLow),
+        ("insufficient funds for gas * price + value", TransactionRejection::InsufficientFunds),
+        ("replacement transaction underpriced", TransactionRejection::Underpriced),
+        ("transaction underpriced", TransactionRejection::Underpriced),
+        ("already known", TransactionRejection::AlreadyKnown),
+        ("known transaction: 6666", TransactionRejection::AlreadyKnown),
+        ("invalid sender", TransactionRejection::Other),
+    ];
+    for (message, expected) in cases.iter() {
+        let text = format!(r#"{{"jsonrpc":"2.0","id":1,"error":{{"code":-32000,"message":"{}"}}}}"#, message);
+        let json = serde_json::from_str::<EvmProxyResponse>(&text).unwrap();
+        match Proxy::transaction_hash_from_response(json) {
+            Err(EvmError::ErrorTransactionRejected(reason, msg)) => {
+                assert_eq!(reason, *expected);
+                assert_eq!(msg, *message);
+            },
+            res => panic!("unexpected result {:?}", res),
+        }
+    }
+}
+
+#[test]
+fn test_estimate_gas_params() {
+    let to = "0x00000000000000000000000000000000000000cc";
+    assert_eq!(Proxy::estimate_gas_params(to, &[], None, None, None), format!("&to={}", to));
+    assert_eq!(
+        Proxy::estimate_gas_params(to, &[0xa9, 0x05, 0x9c, 0xbb], Some(U256::from(1_000_000_000_000_000u64)), Some(U256::from(21000)), Some(U256::from(5_000_000_000u64))),
+        format!("&to={}&data=0xa9059cbb&value=0x38d7ea4c68000&gas=0x5208&gasPrice=0x12a05f200", to));
+
+    let gas = result_from_text::<String>(r#"{"jsonrpc":"2.0","id":1,"result":"0x5208"}"#).unwrap();
+    assert_eq!(parse_hex_quantity_U256(&gas).unwrap(), U256::from(21000));
+}
@@ -0,0 +1,38 @@
+/**
+ * Tests for Transactions related functionality which operate on already
+ * fetched API responses, thus no API key, or network access is needed.
+ */
+#[cfg(test)]
+use crate::prelude::*;
+use crate::types::evm_types::*;
+use crate::api::transactions::Transactions;
+
+#[test]
+fn test_execution_status_from_response() {
+    let json = serde_json::from_str::<EvmTransactionExecutionStatusResponse>(r#"{"status":"1","message":"OK","result":{"isError":"1","errDescription":"Bad jump destination"}}"#).unwrap();
+    let status = Transactions::execution_status_from_response(json).unwrap();
+    assert!(status.is_error);
+    assert_eq!(status.err_description, "Bad jump destination");
+
+    let json = serde_json::from_str::<EvmTransactionExecutionStatusResponse>(r#"{"status":"1","message":"OK","result":{"isError":"0","errDescription":""}}"#).unwrap();
+    let status = Transactions::execution_status_from_response(json).unwrap();
+    assert!(!status.is_error);
+    assert!(status.err_description.is_empty());
+
+    let json = serde_json::from_str::<EvmTransactionExecutionStatusResponse>(r#"{"status":"0","message":"NOTOK","result":"Invalid API Key"}"#).unwrap();
+    match Transactions::execution_status_from_response(json) {
+        Err(EvmError::ErrorApiResponse(msg)) => assert!(msg.contains("Invalid API Key")),
+        res => panic!("unexpected result {:?}", res),
+    }
+}
+
+#[test]
+fn test_receipt_status_from_response() {
+    let parse = |text: &str| Transactions::receipt_status_from_response(serde_json::from_str::<EvmTransactionReceiptStatusResponse>(text).unwrap());
+
+    assert_eq!(parse(r#"{"status":"1","message":"OK","result":{"status":"1"}}"#).unwrap(), Some(true));
+    assert_eq!(parse(r#"{"status":"1","message":"OK","result":{"status":"0"}}"#).unwrap(), Some(false));
+    // pre-Byzantium
+    assert_eq!(parse(r#"{"status":"1","message":"OK","result":{"status":""}}"#).unwrap(), None);
+    assert!(parse(r#"{"status":"0","message":"NOTOK","result":"Max rate limit reached"}"#).is_err());
+}
@@ -1,4 +1,5 @@
 pub mod evm_types;
+pub mod abi_types;
 
 /// List of possible error as occurs from the operations
 #[derive(Debug)]
@@ -23,6 +24,39 @@ pub enum Error {
 
     /// Parameter to function error
     ErrorParameter(Option<String>),
+
+    /// Error in encoding or decoding ABI data
+    /// Contains error message
+    ErrorAbiCoding(String),
+
+    /// Error in following proxy's implementation chain e.g. cycle, or too deep
+    /// Contains error message
+    ErrorProxyResolution(String),
+
+    /// Transaction is rejected by node when it is broadcasted
+    /// Contains reason of rejection, and error message as returned from node
+    ErrorTransactionRejected(TransactionRejection, String),
+}
+
+/// Reason of transaction being rejected by node as categorized from its error
+/// message
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransactionRejection {
+    /// Nonce is lower than the current nonce of sender
+    NonceTooLow,
+
+    /// Sender's balance cannot cover gas * price + value
+    InsufficientFunds,
+
+    /// Gas price is too low, or too low to replace pending transaction with
+    /// the same nonce
+    Underpriced,
+
+    /// The same transaction is already in transaction pool
+    AlreadyKnown,
+
+    /// Other reason, see error message for detail
+    Other,
 }
 
 /// Chain type
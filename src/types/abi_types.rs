@@ -0,0 +1,313 @@
+use crate::prelude::*;
+use crate::types::evm_types::EvmContractABIItemType;
+
+/// 256-bit signed integer in two's complement representation as used by
+/// `intN` type of contract ABI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct I256(pub U256);
+
+impl I256 {
+    /// Create from `i128`.
+    pub fn from_i128(value: i128) -> I256 {
+        let abs = U256::from(value.unsigned_abs());
+        if value < 0 {
+            I256((!abs).overflowing_add(U256::one()).0)
+        }
+        else {
+            I256(abs)
+        }
+    }
+
+    /// Whether the value is negative
+    pub fn is_negative(&self) -> bool {
+        self.0.bit(255)
+    }
+
+    /// Absolute value
+    pub fn abs(&self) -> U256 {
+        if self.is_negative() {
+            (!self.0).overflowing_add(U256::one()).0
+        }
+        else {
+            self.0
+        }
+    }
+
+    /// Convert into `i128`. Return `None` if the value doesn't fit.
+    pub fn to_i128(&self) -> Option<i128> {
+        let abs = self.abs();
+        if abs.bits() > 127 {
+            // i128::MIN is the only value whose absolute value needs 128 bits
+            if self.is_negative() && abs == U256::one() << 127 {
+                return Some(i128::MIN);
+            }
+            return None;
+        }
+
+        let abs = abs.as_u128() as i128;
+        if self.is_negative() { Some(-abs) } else { Some(abs) }
+    }
+}
+
+impl std::fmt::Display for I256 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        if self.is_negative() {
+            write!(f, "-{}", self.abs())
+        }
+        else {
+            write!(f, "{}", self.0)
+        }
+    }
+}
+
+/// Type of contract ABI parameter.
+/// See https://docs.soliditylang.org/en/latest/abi-spec.html#types
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EvmAbiParamType {
+    Address,
+    Bool,
+    String,
+    Bytes,
+
+    /// Address followed by function selector, 24 bytes in total
+    Function,
+
+    /// `uintN` with its number of bits
+    Uint(usize),
+
+    /// `intN` with its number of bits
+    Int(usize),
+
+    /// `bytesN` with its number of bytes
+    FixedBytes(usize),
+
+    /// `T[]`
+    Array(Box<EvmAbiParamType>),
+
+    /// `T[k]`
+    FixedArray(Box<EvmAbiParamType>, usize),
+
+    /// `(T1,T2,...,Tn)`
+    Tuple(Vec<EvmAbiParamType>),
+}
+
+impl EvmAbiParamType {
+    /// Parse canonical type string e.g. `uint256`, `address[]`, or
+    /// `(uint256,bytes)[2]`. `uint`, and `int` are accepted as alias of
+    /// `uint256`, and `int256` respectively. Tuple can optionally be prefixed
+    /// with `tuple` as well.
+    ///
+    /// # Arguments
+    /// * `text` - type string to parse
+    pub fn parse(text: &str) -> Result<EvmAbiParamType, EvmError> {
+        let text = text.trim();
+        let err = || EvmError::ErrorAbiCoding(format!("invalid type '{}'", text));
+
+        if let Some(without_bracket) = text.strip_suffix(']') {
+            let open_index = without_bracket.rfind('[').ok_or_else(err)?;
+            let inner = EvmAbiParamType::parse(&without_bracket[..open_index])?;
+            let dimension = &without_bracket[open_index+1..];
+            if dimension.is_empty() {
+                return Ok(EvmAbiParamType::Array(Box::new(inner)));
+            }
+            let size = dimension.parse::<usize>().map_err(|_| err())?;
+            return Ok(EvmAbiParamType::FixedArray(Box::new(inner), size));
+        }
+
+        let tuple_text = text.strip_prefix("tuple").unwrap_or(text);
+        if let Some(members) = tuple_text.strip_prefix('(').and_then(|t| t.strip_suffix(')')) {
+            let mut components = Vec::new();
+            for member in EvmAbiParamType::split_top_level(members) {
+                components.push(EvmAbiParamType::parse(member)?);
+            }
+            return Ok(EvmAbiParamType::Tuple(components));
+        }
+
+        match text {
+            "address" => return Ok(EvmAbiParamType::Address),
+            "bool" => return Ok(EvmAbiParamType::Bool),
+            "string" => return Ok(EvmAbiParamType::String),
+            "bytes" => return Ok(EvmAbiParamType::Bytes),
+            "function" => return Ok(EvmAbiParamType::Function),
+            "uint" => return Ok(EvmAbiParamType::Uint(256)),
+            "int" => return Ok(EvmAbiParamType::Int(256)),
+            _ => (),
+        }
+
+        let (prefix, size_text) = match text.find(|c: char| c.is_ascii_digit()) {
+            Some(index) => text.split_at(index),
+            None => return Err(err()),
+        };
+        let size = size_text.parse::<usize>().map_err(|_| err())?;
+        match prefix {
+            "uint" if size > 0 && size <= 256 && size % 8 == 0 => Ok(EvmAbiParamType::Uint(size)),
+            "int" if size > 0 && size <= 256 && size % 8 == 0 => Ok(EvmAbiParamType::Int(size)),
+            "bytes" if size > 0 && size <= 32 => Ok(EvmAbiParamType::FixedBytes(size)),
+            _ => Err(err()),
+        }
+    }
+
+    /// Create from input or output of contract ABI item. Type of `tuple` is
+    /// resolved from its `components`.
+    ///
+    /// # Arguments
+    /// * `param` - input or output of contract ABI item
+    pub fn from_abi_param(param: &EvmContractABIItemType) -> Result<EvmAbiParamType, EvmError> {
+        let suffix = match param.r#type.strip_prefix("tuple") {
+            Some(suffix) => suffix,
+            None => return EvmAbiParamType::parse(&param.r#type),
+        };
+
+        let components = match param.components {
+            Some(ref components) => components,
+            None => return Err(EvmError::ErrorAbiCoding(format!("missing components of '{}' for '{}'", param.r#type, param.name))),
+        };
+        let mut res = EvmAbiParamType::Tuple(EvmAbiParamType::from_abi_params(components)?);
+
+        // apply array dimensions from left to right e.g. tuple[2][] is
+        // dynamic array of fixed array of 2 tuples
+        for dimension in suffix.split_terminator(']') {
+            let dimension = match dimension.strip_prefix('[') {
+                Some(d) => d,
+                None => return Err(EvmError::ErrorAbiCoding(format!("invalid type '{}'", param.r#type))),
+            };
+            if dimension.is_empty() {
+                res = EvmAbiParamType::Array(Box::new(res));
+            }
+            else {
+                let size = dimension.parse::<usize>().map_err(|_| EvmError::ErrorAbiCoding(format!("invalid type '{}'", param.r#type)))?;
+                res = EvmAbiParamType::FixedArray(Box::new(res), size);
+            }
+        }
+
+        Ok(res)
+    }
+
+    /// Create from list of inputs or outputs of contract ABI item.
+    ///
+    /// # Arguments
+    /// * `params` - inputs or outputs of contract ABI item
+    pub fn from_abi_params(params: &[EvmContractABIItemType]) -> Result<Vec<EvmAbiParamType>, EvmError> {
+        params.iter().map(EvmAbiParamType::from_abi_param).collect()
+    }
+
+    /// Canonical type string as used in function, event, and error signature.
+    pub fn canonical(&self) -> String {
+        match self {
+            EvmAbiParamType::Address => "address".to_owned(),
+            EvmAbiParamType::Bool => "bool".to_owned(),
+            EvmAbiParamType::String => "string".to_owned(),
+            EvmAbiParamType::Bytes => "bytes".to_owned(),
+            EvmAbiParamType::Function => "function".to_owned(),
+            EvmAbiParamType::Uint(bits) => format!("uint{}", bits),
+            EvmAbiParamType::Int(bits) => format!("int{}", bits),
+            EvmAbiParamType::FixedBytes(size) => format!("bytes{}", size),
+            EvmAbiParamType::Array(inner) => format!("{}[]", inner.canonical()),
+            EvmAbiParamType::FixedArray(inner, size) => format!("{}[{}]", inner.canonical(), size),
+            EvmAbiParamType::Tuple(components) => {
+                let members: Vec<String> = components.iter().map(|c| c.canonical()).collect();
+                format!("({})", members.join(","))
+            },
+        }
+    }
+
+    /// Whether the type is dynamic i.e. its data is encoded in the tail part
+    pub fn is_dynamic(&self) -> bool {
+        match self {
+            EvmAbiParamType::String | EvmAbiParamType::Bytes | EvmAbiParamType::Array(_) => true,
+            EvmAbiParamType::FixedArray(inner, _) => inner.is_dynamic(),
+            EvmAbiParamType::Tuple(components) => components.iter().any(|c| c.is_dynamic()),
+            _ => false,
+        }
+    }
+
+    /// Number of bytes the type occupies in the head part
+    pub fn head_size(&self) -> usize {
+        if self.is_dynamic() {
+            return 32;
+        }
+        match self {
+            EvmAbiParamType::FixedArray(inner, size) => inner.head_size() * size,
+            EvmAbiParamType::Tuple(components) => components.iter().map(|c| c.head_size()).sum(),
+            _ => 32,
+        }
+    }
+
+    /// Split text by comma which is not nested inside parentheses.
+    pub(crate) fn split_top_level(text: &str) -> Vec<&str> {
+        let mut res = Vec::new();
+        if text.trim().is_empty() {
+            return res;
+        }
+
+        let mut depth = 0i32;
+        let mut start = 0usize;
+        for (i, c) in text.char_indices() {
+            match c {
+                '(' => depth += 1,
+                ')' => depth -= 1,
+                ',' if depth == 0 => {
+                    res.push(&text[start..i]);
+                    start = i + 1;
+                },
+                _ => (),
+            }
+        }
+        res.push(&text[start..]);
+        res
+    }
+}
+
+impl std::fmt::Display for EvmAbiParamType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", self.canonical())
+    }
+}
+
+/// Value of contract ABI parameter
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EvmAbiValue {
+    Address(H160),
+    Bool(bool),
+    String(String),
+    Bytes(Vec<u8>),
+
+    /// Value of `function` type which is address followed by function selector
+    Function(H160, [u8; 4]),
+
+    Uint(U256),
+    Int(I256),
+    FixedBytes(Vec<u8>),
+    Array(Vec<EvmAbiValue>),
+    FixedArray(Vec<EvmAbiValue>),
+    Tuple(Vec<EvmAbiValue>),
+}
+
+/// Decoded value of contract ABI parameter along with its name, and type
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EvmAbiNamedValue {
+    /// Parameter name. It can be empty.
+    pub name: String,
+
+    /// Parameter type
+    pub param_type: EvmAbiParamType,
+
+    /// Decoded value
+    pub value: EvmAbiValue,
+}
+
+/// Decoded function call from transaction's input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EvmAbiDecodedCall {
+    /// Function name
+    pub name: String,
+
+    /// Canonical function signature e.g. `transfer(address,uint256)`
+    pub signature: String,
+
+    /// 4-byte function selector
+    pub selector: [u8; 4],
+
+    /// Decoded arguments
+    pub inputs: Vec<EvmAbiNamedValue>,
+}
@@ -2,7 +2,17 @@ use crate::prelude::*;
 use crate::deserialize::{de_string_to_numeric,
                          de_string_to_U256,
                          de_string_to_bool,
-                         de_constructor_arguments_string_to_vec_string};
+                         de_constructor_arguments_string_to_vec_string,
+                         de_sources_map_to_vec_source_file,
+                         ser_vec_source_file_to_sources_map,
+                         de_hex_string_to_u64,
+                         de_hex_string_to_U256,
+                         de_opt_hex_string_to_u64,
+                         de_opt_hex_string_to_U256,
+                         de_comma_separated_string_to_vec_f64,
+                         parse_abi,
+                         parse_libraries};
+use crate::types::abi_types::EvmAbiNamedValue;
 
 /// Type of upstream server's API request
 pub enum EvmApiResponseType {
@@ -362,37 +372,89 @@ pub struct EvmContractABIResponse {
     pub result: String,
 }
 
+/// Kind of individual contract ABI item as seen in its `type` field.
+/// It is `Function` if such field is omitted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EvmContractABIItemKind {
+    #[default]
+    Function,
+    Constructor,
+    Receive,
+    Fallback,
+    Event,
+    Error,
+}
+
+/// State mutability of function, constructor, receive, and fallback ABI item
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EvmContractStateMutability {
+    Pure,
+    View,
+    NonPayable,
+    Payable,
+}
+
 /// Actual structure holding individual contract ABI.
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
+/// See https://docs.soliditylang.org/en/latest/abi-spec.html#json
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct EvmContractABIItem {
+pub struct EvmContractABIItem {
+    /// Whether event was declared as `anonymous`
     #[serde(skip_serializing_if = "Option::is_none")]
     pub anonymous: Option<bool>,
 
+    /// Legacy field, superseded by `state_mutability`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub constant: Option<bool>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub inputs: Option<Vec<EvmContractABIItemType>>,
 
+    /// Name of function, event, or error. Not available for constructor,
+    /// receive, and fallback.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub outputs: Option<Vec<EvmContractABIItemType>>,
 
+    /// Legacy field, superseded by `state_mutability`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payable: Option<bool>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state_mutability: Option<String>,
+    pub state_mutability: Option<EvmContractStateMutability>,
 
-    pub r#type: String
+    #[serde(default)]
+    pub r#type: EvmContractABIItemKind,
+
+    /// Any other fields not part of the specification e.g. `gas` as emitted
+    /// by older Vyper compiler. Kept in order not to lose information.
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
-/// Type definition for each ABI item
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
+/// Type definition for each ABI item's input or output
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct EvmContractABIItemType {
+pub struct EvmContractABIItemType {
+    /// Members of tuple type; only available when `type` is tuple, or array of tuple
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub components: Option<Vec<EvmContractABIItemType>>,
+
+    /// Whether event's input is indexed i.e. part of topics
     #[serde(skip_serializing_if = "Option::is_none")]
     pub indexed: Option<bool>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub internal_type: Option<String>,
+
+    #[serde(default)]
     pub name: String,
+
+    /// Canonical type e.g. `uint256`, `address[]`, or `tuple[2]`
     pub r#type: String,
 }
 
@@ -418,9 +480,153 @@ pub enum EvmContractSourceCodeResult {
     Failed(String),
 }
 
+/// Contract creation response
+#[derive(Debug, serde::Deserialize)]
+pub struct EvmContractCreationResponse {
+    pub status: String,
+    pub message: String,
+
+    /// It can be `null` when there is no data found
+    #[serde(default)]
+    pub result: Option<EvmContractCreationResult>,
+}
+
+/// Structure holding variant response for field `result` of Contracts's
+/// getting contract creation API.
+#[derive(Debug, serde::Deserialize)]
+#[serde(untagged)]
+pub enum EvmContractCreationResult {
+    Success(Vec<EvmContractCreation>),
+    Failed(String),
+}
+
+/// Creator, and creation transaction of a contract
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvmContractCreation {
+    /// Contract address
+    pub contract_address: String,
+
+    /// Address which deployed the contract
+    pub contract_creator: String,
+
+    /// Transaction hash in which the contract was created
+    pub tx_hash: String,
+}
+
+/// Format of source code submitted for verification
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvmContractCodeFormat {
+    /// Single, possibly flattened Solidity file
+    SoliditySingleFile,
+
+    /// Solidity's Standard JSON input
+    SolidityStandardJsonInput,
+}
+
+impl EvmContractCodeFormat {
+    /// Value of `codeformat` field as accepted by API
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EvmContractCodeFormat::SoliditySingleFile => "solidity-single-file",
+            EvmContractCodeFormat::SolidityStandardJsonInput => "solidity-standard-json-input",
+        }
+    }
+}
+
+/// Request to verify contract's source code
+#[derive(Debug, Clone)]
+pub struct EvmContractVerificationRequest {
+    /// Contract address to verify
+    pub contract_address: String,
+
+    /// Source code in the format as of `code_format`
+    pub source_code: String,
+
+    /// Format of `source_code`
+    pub code_format: EvmContractCodeFormat,
+
+    /// Contract name. For Standard JSON input, it has to be in form of
+    /// `<path>:<name>` e.g. `contracts/Vault.sol:Vault`.
+    pub contract_name: String,
+
+    /// Full compiler version e.g. `v0.8.12+commit.f00d7308`
+    pub compiler_version: String,
+
+    /// Whether or not optimization has been applied. Only used for single file.
+    pub optimization_used: bool,
+
+    /// Number of runs as part of optimization. Only used for single file.
+    pub runs: u32,
+
+    /// ABI encoded constructor's arguments in hexadecimal string without `0x`
+    pub constructor_arguments: String,
+
+    /// EVM version, `None` for compiler's default. Only used for single file.
+    pub evm_version: Option<String>,
+
+    /// License type code as listed at https://etherscan.io/contract-license-types
+    /// e.g. 1 for no license, 3 for MIT.
+    pub license_type: Option<u8>,
+
+    /// Linked libraries as pair of name, and address. At most 10 libraries.
+    /// Only used for single file.
+    pub libraries: Vec<(String, String)>,
+}
+
+impl EvmContractVerificationRequest {
+    /// Create a new request with no optimization, no constructor's arguments,
+    /// and no libraries.
+    ///
+    /// # Arguments
+    /// * `contract_address` - contract address to verify
+    /// * `source_code` - source code
+    /// * `code_format` - format of source code
+    /// * `contract_name` - contract name
+    /// * `compiler_version` - full compiler version
+    pub fn create(contract_address: &str, source_code: &str, code_format: EvmContractCodeFormat, contract_name: &str, compiler_version: &str) -> EvmContractVerificationRequest {
+        EvmContractVerificationRequest {
+            contract_address: contract_address.to_owned(),
+            source_code: source_code.to_owned(),
+            code_format,
+            contract_name: contract_name.to_owned(),
+            compiler_version: compiler_version.to_owned(),
+            optimization_used: false,
+            runs: 200,
+            constructor_arguments: String::new(),
+            evm_version: None,
+            license_type: None,
+            libraries: Vec::new(),
+        }
+    }
+}
+
+/// Response of submitting verification, and checking its status
+#[derive(Debug, serde::Deserialize)]
+pub struct EvmContractVerificationResponse {
+    pub status: String,
+    pub message: String,
+
+    /// GUID for submission, or status message for checking
+    pub result: String,
+}
+
+/// Status of submitted verification
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EvmContractVerificationStatus {
+    /// Still in queue, or being processed
+    Pending,
+
+    /// Successfully verified, or it has already been verified
+    Pass,
+
+    /// Failed with the reason as returned from API
+    Fail(String),
+}
+
 /// Actual structure holding contract's verified source code
 /// If such contract doesn't verify source code, then most fields will be empty.
-#[derive(Debug, serde::Deserialize)]
+#[derive(Debug, Clone, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct EvmContractSourceCode {
     /// Actual smart contract source code
@@ -458,9 +664,8 @@ pub struct EvmContractSourceCode {
     #[serde(rename = "EVMVersion")]
     pub evm_version: String,
 
-    /// Library used by this constract
-    /// FIXME: For now, returned as a whole as string, we need to find an example of
-    /// contract which contains non-empty of this field.
+    /// Library used by this constract as returned as a whole as string.
+    /// See `parsed_libraries()` for its parsed form.
     #[serde(rename = "Library")]
     pub library: String,
 
@@ -479,7 +684,1512 @@ pub struct EvmContractSourceCode {
     #[serde(rename = "Implementation")]
     pub implementation: String,
 
-    /// URL to swarm source
+    /// URL to swarm source. See `parsed_swarm_source()` for its parsed form.
     #[serde(rename = "SwarmSource")]
     pub swarm_source: String,
+
+    /// Language of source code as detected from compiler version, or
+    /// `language` field of Standard JSON input. It is not part of API
+    /// response, but filled in by `Contracts::get_verified_source_code()`.
+    #[serde(skip)]
+    pub language: EvmContractLanguage,
+}
+
+impl EvmContractSourceCode {
+    /// Parse `compiler_version` into typed compiler version.
+    pub fn parsed_compiler_version(&self) -> Result<EvmCompilerVersion, EvmError> {
+        EvmCompilerVersion::parse(&self.compiler_version)
+    }
+
+    /// Parse `evm_version` into typed EVM version.
+    pub fn parsed_evm_version(&self) -> EvmVersion {
+        EvmVersion::parse(&self.evm_version)
+    }
+
+    /// Parse `library` into map of library name to its address prefixed with `0x`.
+    pub fn parsed_libraries(&self) -> Result<std::collections::BTreeMap<String, String>, EvmError> {
+        parse_libraries(&self.library)
+    }
+
+    /// Parse `license_type` into typed license.
+    pub fn parsed_license_type(&self) -> EvmContractLicense {
+        EvmContractLicense::parse(&self.license_type)
+    }
+
+    /// Parse `swarm_source` into its scheme, and hash.
+    pub fn parsed_swarm_source(&self) -> EvmSwarmSource {
+        EvmSwarmSource::parse(&self.swarm_source)
+    }
+
+    /// Decode constructor's arguments into named, and typed values according
+    /// to contract's own ABI. See `abi::decode::decode_constructor_arguments()`.
+    pub fn decode_constructor_arguments(&self) -> Result<Vec<EvmAbiNamedValue>, EvmError> {
+        let abi = parse_abi(&self.abi)?;
+        crate::abi::decode::decode_constructor_arguments(&abi, &self.constructor_arguments.concat())
+    }
+}
+
+/// Kind of compiler
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum EvmCompilerKind {
+    Solc,
+    Vyper,
+}
+
+/// Compiler version as used to verify contract's source code.
+///
+/// It can be used to group, and order contracts as it implements `Eq`, `Hash`,
+/// and `Ord`. Version follows semantic versioning, so it can be matched
+/// against `semver::VersionReq` e.g. to flag known buggy releases.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EvmCompilerVersion {
+    /// Kind of compiler
+    pub kind: EvmCompilerKind,
+
+    /// Version e.g. `0.8.12`, or `0.4.24-nightly.2018.5.16` for nightly build
+    pub version: semver::Version,
+
+    /// Commit hash of the compiler build if known e.g. `f00d7308`
+    pub commit: Option<String>,
+}
+
+impl EvmCompilerVersion {
+    /// Parse compiler version as returned from API e.g. `v0.8.12+commit.f00d7308`
+    /// for solc, or `vyper:0.3.7` for vyper.
+    ///
+    /// # Arguments
+    /// * `text` - compiler version
+    pub fn parse(text: &str) -> Result<EvmCompilerVersion, EvmError> {
+        let text = text.trim();
+        let err = |msg: String| EvmError::ErrorParameter(Some(format!("invalid compiler version '{}'; {}", text, msg)));
+
+        let (kind, rest) = match text.strip_prefix("vyper:") {
+            Some(rest) => (EvmCompilerKind::Vyper, rest),
+            None => (EvmCompilerKind::Solc, text),
+        };
+        let rest = rest.trim_start_matches('v');
+
+        let (version, commit) = match rest.split_once('+') {
+            Some((version, build)) => (version, Some(build.strip_prefix("commit.").unwrap_or(build).to_owned())),
+            None => (rest, None),
+        };
+
+        // nightly builds have date with leading zero e.g. `nightly.2018.05.16`
+        // which is not allowed for numeric identifier of semantic versioning
+        let version = match version.split_once('-') {
+            Some((core, pre)) => {
+                let pre: Vec<&str> = pre.split('.')
+                    .map(|p| if p.len() > 1 && p.bytes().all(|b| b.is_ascii_digit()) { p.trim_start_matches('0') } else { p })
+                    .collect();
+                format!("{}-{}", core, pre.join("."))
+            },
+            None => version.to_owned(),
+        };
+
+        let version = semver::Version::parse(&version).map_err(|e| err(e.to_string()))?;
+        Ok(EvmCompilerVersion { kind, version, commit })
+    }
+
+    /// Whether this is a nightly, or other pre-release build
+    pub fn is_prerelease(&self) -> bool {
+        !self.version.pre.is_empty()
+    }
+}
+
+impl std::fmt::Display for EvmCompilerVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match (self.kind, &self.commit) {
+            (EvmCompilerKind::Solc, Some(commit)) => write!(f, "v{}+commit.{}", self.version, commit),
+            (EvmCompilerKind::Solc, None) => write!(f, "v{}", self.version),
+            (EvmCompilerKind::Vyper, Some(commit)) => write!(f, "vyper:{}+commit.{}", self.version, commit),
+            (EvmCompilerKind::Vyper, None) => write!(f, "vyper:{}", self.version),
+        }
+    }
+}
+
+/// EVM version targeted by the compiler
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EvmVersion {
+    /// Compiler's default EVM version
+    Default,
+    Homestead,
+    TangerineWhistle,
+    SpuriousDragon,
+    Byzantium,
+    Constantinople,
+    Petersburg,
+    Istanbul,
+    Berlin,
+    London,
+    Paris,
+    Shanghai,
+    Cancun,
+    Prague,
+
+    /// Any other EVM version as-is
+    Other(String),
+}
+
+impl EvmVersion {
+    /// Parse EVM version as returned from API e.g. `Default`, or `london`.
+    /// Empty text is treated as `Default`.
+    ///
+    /// # Arguments
+    /// * `text` - EVM version
+    pub fn parse(text: &str) -> EvmVersion {
+        match text.trim().to_lowercase().as_str() {
+            "" | "default" => EvmVersion::Default,
+            "homestead" => EvmVersion::Homestead,
+            "tangerinewhistle" => EvmVersion::TangerineWhistle,
+            "spuriousdragon" => EvmVersion::SpuriousDragon,
+            "byzantium" => EvmVersion::Byzantium,
+            "constantinople" => EvmVersion::Constantinople,
+            "petersburg" => EvmVersion::Petersburg,
+            "istanbul" => EvmVersion::Istanbul,
+            "berlin" => EvmVersion::Berlin,
+            "london" => EvmVersion::London,
+            "paris" => EvmVersion::Paris,
+            "shanghai" => EvmVersion::Shanghai,
+            "cancun" => EvmVersion::Cancun,
+            "prague" => EvmVersion::Prague,
+            _ => EvmVersion::Other(text.trim().to_owned()),
+        }
+    }
+
+    /// Name as used by solc e.g. `london`. Return `None` for `Default`.
+    pub fn as_solc_str(&self) -> Option<&str> {
+        match self {
+            EvmVersion::Default => None,
+            EvmVersion::Homestead => Some("homestead"),
+            EvmVersion::TangerineWhistle => Some("tangerineWhistle"),
+            EvmVersion::SpuriousDragon => Some("spuriousDragon"),
+            EvmVersion::Byzantium => Some("byzantium"),
+            EvmVersion::Constantinople => Some("constantinople"),
+            EvmVersion::Petersburg => Some("petersburg"),
+            EvmVersion::Istanbul => Some("istanbul"),
+            EvmVersion::Berlin => Some("berlin"),
+            EvmVersion::London => Some("london"),
+            EvmVersion::Paris => Some("paris"),
+            EvmVersion::Shanghai => Some("shanghai"),
+            EvmVersion::Cancun => Some("cancun"),
+            EvmVersion::Prague => Some("prague"),
+            EvmVersion::Other(text) => Some(text),
+        }
+    }
+}
+
+/// License of verified contract's source code as listed at
+/// https://etherscan.io/contract-license-types
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EvmContractLicense {
+    /// No license
+    None,
+    Unlicense,
+    Mit,
+    GplV2,
+    GplV3,
+    LgplV2_1,
+    LgplV3,
+    Bsd2Clause,
+    Bsd3Clause,
+    Mpl2,
+    Osl3,
+    Apache2,
+    AgplV3,
+    BusinessSource1_1,
+
+    /// Any other license as-is
+    Other(String),
+}
+
+impl EvmContractLicense {
+    /// Parse license as returned from API e.g. `MIT`, or `GNU GPLv3`. SPDX
+    /// identifier e.g. `GPL-3.0-or-later` is also accepted.
+    ///
+    /// # Arguments
+    /// * `text` - license type
+    pub fn parse(text: &str) -> EvmContractLicense {
+        let normalized = text.trim().to_lowercase();
+        let normalized = normalized.trim_end_matches("-only").trim_end_matches("-or-later");
+        match normalized {
+            // SPDX `UNLICENSED` means proprietary, unlike The Unlicense
+            "" | "none" | "no license" | "unlicensed" => EvmContractLicense::None,
+            "unlicense" => EvmContractLicense::Unlicense,
+            "mit" => EvmContractLicense::Mit,
+            "gnu gplv2" | "gpl-2.0" => EvmContractLicense::GplV2,
+            "gnu gplv3" | "gpl-3.0" => EvmContractLicense::GplV3,
+            "gnu lgplv2.1" | "lgpl-2.1" => EvmContractLicense::LgplV2_1,
+            "gnu lgplv3" | "lgpl-3.0" => EvmContractLicense::LgplV3,
+            "bsd-2-clause" => EvmContractLicense::Bsd2Clause,
+            "bsd-3-clause" => EvmContractLicense::Bsd3Clause,
+            "mpl-2.0" => EvmContractLicense::Mpl2,
+            "osl-3.0" => EvmContractLicense::Osl3,
+            "apache-2.0" => EvmContractLicense::Apache2,
+            "gnu agplv3" | "agpl-3.0" => EvmContractLicense::AgplV3,
+            "bsl 1.1" | "busl-1.1" => EvmContractLicense::BusinessSource1_1,
+            _ => EvmContractLicense::Other(text.trim().to_owned()),
+        }
+    }
+
+    /// SPDX identifier. Return `None` for no license, or unknown license.
+    pub fn spdx_id(&self) -> Option<&'static str> {
+        match self {
+            EvmContractLicense::None | EvmContractLicense::Other(_) => None,
+            EvmContractLicense::Unlicense => Some("Unlicense"),
+            EvmContractLicense::Mit => Some("MIT"),
+            EvmContractLicense::GplV2 => Some("GPL-2.0"),
+            EvmContractLicense::GplV3 => Some("GPL-3.0"),
+            EvmContractLicense::LgplV2_1 => Some("LGPL-2.1"),
+            EvmContractLicense::LgplV3 => Some("LGPL-3.0"),
+            EvmContractLicense::Bsd2Clause => Some("BSD-2-Clause"),
+            EvmContractLicense::Bsd3Clause => Some("BSD-3-Clause"),
+            EvmContractLicense::Mpl2 => Some("MPL-2.0"),
+            EvmContractLicense::Osl3 => Some("OSL-3.0"),
+            EvmContractLicense::Apache2 => Some("Apache-2.0"),
+            EvmContractLicense::AgplV3 => Some("AGPL-3.0"),
+            EvmContractLicense::BusinessSource1_1 => Some("BUSL-1.1"),
+        }
+    }
+
+    /// License type code as accepted by `licenseType` when verifying source
+    /// code. Return `None` for unknown license.
+    pub fn code(&self) -> Option<u8> {
+        match self {
+            EvmContractLicense::None => Some(1),
+            EvmContractLicense::Unlicense => Some(2),
+            EvmContractLicense::Mit => Some(3),
+            EvmContractLicense::GplV2 => Some(4),
+            EvmContractLicense::GplV3 => Some(5),
+            EvmContractLicense::LgplV2_1 => Some(6),
+            EvmContractLicense::LgplV3 => Some(7),
+            EvmContractLicense::Bsd2Clause => Some(8),
+            EvmContractLicense::Bsd3Clause => Some(9),
+            EvmContractLicense::Mpl2 => Some(10),
+            EvmContractLicense::Osl3 => Some(11),
+            EvmContractLicense::Apache2 => Some(12),
+            EvmContractLicense::AgplV3 => Some(13),
+            EvmContractLicense::BusinessSource1_1 => Some(14),
+            EvmContractLicense::Other(_) => None,
+        }
+    }
+}
+
+/// Location of contract's metadata as embedded in its bytecode
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EvmSwarmSource {
+    /// No swarm source
+    None,
+
+    /// Swarm hash of `bzzr://` scheme
+    Bzzr(String),
+
+    /// IPFS hash of `ipfs://` scheme
+    Ipfs(String),
+
+    /// Any other swarm source as-is
+    Other(String),
+}
+
+impl EvmSwarmSource {
+    /// Parse swarm source as returned from API e.g. `bzzr://<hash>`, or
+    /// `ipfs://<hash>`. Empty text is treated as `None`.
+    ///
+    /// # Arguments
+    /// * `text` - swarm source
+    pub fn parse(text: &str) -> EvmSwarmSource {
+        let text = text.trim();
+        if text.is_empty() {
+            return EvmSwarmSource::None;
+        }
+
+        match text.split_once("://") {
+            Some((scheme, hash)) if scheme.eq_ignore_ascii_case("bzzr") => EvmSwarmSource::Bzzr(hash.to_owned()),
+            Some((scheme, hash)) if scheme.eq_ignore_ascii_case("ipfs") => EvmSwarmSource::Ipfs(hash.to_owned()),
+            _ => EvmSwarmSource::Other(text.to_owned()),
+        }
+    }
+
+    /// Hash without its scheme. Return `None` for `None`, and `Other`.
+    pub fn hash(&self) -> Option<&str> {
+        match self {
+            EvmSwarmSource::Bzzr(hash) | EvmSwarmSource::Ipfs(hash) => Some(hash),
+            EvmSwarmSource::None | EvmSwarmSource::Other(_) => None,
+        }
+    }
+}
+
+/// Single source file of verified contract
+#[derive(Debug, Clone, PartialEq)]
+pub struct EvmContractSourceFile {
+    /// Path of the file as it is used in import statement
+    pub path: String,
+
+    /// Content of the file
+    pub content: String,
+}
+
+/// Optimizer settings of Solidity's Standard JSON input
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvmContractOptimizerSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub runs: Option<u32>,
+
+    /// Fine-grained optimizer switches kept as-is
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<serde_json::Value>,
+}
+
+/// `settings` field of Solidity's Standard JSON input.
+/// See https://docs.soliditylang.org/en/latest/using-the-compiler.html#input-description
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvmContractCompilerSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optimizer: Option<EvmContractOptimizerSettings>,
+
+    /// Import remappings in form of `prefix=target`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub remappings: Vec<String>,
+
+    /// Linked libraries; map of source path to map of library name to its address
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub libraries: std::collections::BTreeMap<String, std::collections::BTreeMap<String, String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evm_version: Option<String>,
+
+    /// Whether to compile via IR pipeline
+    #[serde(rename = "viaIR")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub via_ir: Option<bool>,
+
+    /// Any other settings e.g. `outputSelection`, and `metadata` kept as-is
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Solidity's, or Vyper's Standard JSON input as submitted for verification.
+/// It is also used for the case of submitting only map of source files in
+/// which `language` is "Solidity", and `settings` is `None`.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct EvmContractStandardJsonInput {
+    #[serde(default = "EvmContractStandardJsonInput::default_language")]
+    pub language: String,
+
+    /// Source files in order as they appear in the input
+    #[serde(deserialize_with = "de_sources_map_to_vec_source_file")]
+    #[serde(serialize_with = "ser_vec_source_file_to_sources_map")]
+    pub sources: Vec<EvmContractSourceFile>,
+
+    /// Interface files of Vyper in order as they appear in the input.
+    /// JSON interface is kept as content in JSON text.
+    #[serde(default)]
+    #[serde(deserialize_with = "de_sources_map_to_vec_source_file")]
+    #[serde(serialize_with = "ser_vec_source_file_to_sources_map")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub interfaces: Vec<EvmContractSourceFile>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub settings: Option<EvmContractCompilerSettings>,
+}
+
+impl EvmContractStandardJsonInput {
+    fn default_language() -> String {
+        "Solidity".to_owned()
+    }
+}
+
+/// Language of contract's source code
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub enum EvmContractLanguage {
+    #[default]
+    Solidity,
+    Vyper,
+    Yul,
+
+    /// Any other language as-is
+    Other(String),
+}
+
+impl EvmContractLanguage {
+    /// Parse `language` field of Standard JSON input e.g. `Solidity`, or `Vyper`.
+    ///
+    /// # Arguments
+    /// * `text` - language
+    pub fn parse(text: &str) -> EvmContractLanguage {
+        match text.trim().to_lowercase().as_str() {
+            "solidity" => EvmContractLanguage::Solidity,
+            "vyper" => EvmContractLanguage::Vyper,
+            "yul" => EvmContractLanguage::Yul,
+            _ => EvmContractLanguage::Other(text.trim().to_owned()),
+        }
+    }
+
+    /// Name as used in `language` field of Standard JSON input
+    pub fn as_str(&self) -> &str {
+        match self {
+            EvmContractLanguage::Solidity => "Solidity",
+            EvmContractLanguage::Vyper => "Vyper",
+            EvmContractLanguage::Yul => "Yul",
+            EvmContractLanguage::Other(text) => text,
+        }
+    }
+
+    /// File extension of source file without leading `.`
+    pub fn file_extension(&self) -> &str {
+        match self {
+            EvmContractLanguage::Vyper => "vy",
+            EvmContractLanguage::Yul => "yul",
+            _ => "sol",
+        }
+    }
+}
+
+/// Verified contract's source code split into files along with its compiler
+/// settings.
+#[derive(Debug, Clone)]
+pub struct EvmContractVerifiedSource {
+    /// Contract's metadata as returned from API. Its `source_code` is kept as-is.
+    pub contract: EvmContractSourceCode,
+
+    /// Language as detected from compiler version, or Standard JSON input
+    pub language: EvmContractLanguage,
+
+    /// Whether source code was submitted as Standard JSON input, or map of
+    /// source files
+    pub is_standard_json: bool,
+
+    /// Source files. For single-file source code, there is only one file whose
+    /// path is contract name with extension of its language e.g. `.sol`.
+    pub sources: Vec<EvmContractSourceFile>,
+
+    /// Interface files of Vyper. Empty for other languages.
+    pub interfaces: Vec<EvmContractSourceFile>,
+
+    /// Compiler settings as submitted. Only available for Standard JSON input.
+    pub settings: Option<EvmContractCompilerSettings>,
+}
+
+/// Kind of change of a source file between two verified contracts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvmSourceFileChangeKind {
+    Added,
+    Removed,
+    Modified,
+}
+
+/// Change of a source file between two verified contracts
+#[derive(Debug, Clone, PartialEq)]
+pub struct EvmSourceFileDiff {
+    /// Path of the file in old contract, `None` if it is added
+    pub old_path: Option<String>,
+
+    /// Path of the file in new contract, `None` if it is removed
+    pub new_path: Option<String>,
+
+    /// Kind of change
+    pub kind: EvmSourceFileChangeKind,
+
+    /// Unified diff of the file's content
+    pub unified_diff: String,
+}
+
+/// Change of a compiler setting between two verified contracts. Nested
+/// settings are named with `.` e.g. `optimizer.runs`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EvmSettingDiff {
+    /// Name of setting
+    pub name: String,
+
+    /// Value in old contract, `None` if it is not set
+    pub old: Option<String>,
+
+    /// Value in new contract, `None` if it is not set
+    pub new: Option<String>,
+}
+
+/// Kind of change of an ABI item between two verified contracts
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EvmAbiChangeKind {
+    Added,
+    Removed,
+
+    /// State mutability changed from old to new
+    MutabilityChanged(Option<EvmContractStateMutability>, Option<EvmContractStateMutability>),
+
+    /// Types of outputs changed
+    OutputsChanged,
+
+    /// Types of inputs changed. Only for constructor as other items with
+    /// different inputs are considered different items.
+    InputsChanged,
+}
+
+/// Change of an ABI item between two verified contracts
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EvmAbiItemChange {
+    /// Type of ABI item
+    pub item_type: EvmContractABIItemKind,
+
+    /// Canonical signature of ABI item, empty for constructor, receive, and fallback
+    pub signature: String,
+
+    /// Kind of change
+    pub kind: EvmAbiChangeKind,
+}
+
+/// Differences between verified source code of two contracts
+#[derive(Debug, Clone, PartialEq)]
+pub struct EvmContractSourceDiff {
+    /// Changed source files. Unchanged files are not included.
+    pub files: Vec<EvmSourceFileDiff>,
+
+    /// Changed compiler settings
+    pub settings: Vec<EvmSettingDiff>,
+
+    /// Changed ABI items
+    pub abi: Vec<EvmAbiItemChange>,
+}
+
+impl EvmContractSourceDiff {
+    /// Whether there is no difference at all
+    pub fn is_identical(&self) -> bool {
+        self.files.is_empty() && self.settings.is_empty() && self.abi.is_empty()
+    }
+}
+
+/// Single level of proxy's implementation chain
+#[derive(Debug, Clone)]
+pub struct EvmContractProxyLevel {
+    /// Contract address of this level
+    pub address: String,
+
+    /// Verified source code of this level
+    pub source: EvmContractVerifiedSource,
+
+    /// ABI of this level
+    pub abi: Vec<EvmContractABIItem>,
+}
+
+/// Proxy contract resolved through its implementation chain
+#[derive(Debug, Clone)]
+pub struct EvmContractProxyResolution {
+    /// Levels starting from the queried address, followed by its
+    /// implementation, and so on. The last level is not a proxy.
+    pub levels: Vec<EvmContractProxyLevel>,
+
+    /// ABI of all levels merged together without duplicates, suitable for
+    /// decoding calls made to the queried address. When the same item appears
+    /// in multiple levels, the one closer to the queried address is kept as
+    /// it is the one dispatched first.
+    pub merged_abi: Vec<EvmContractABIItem>,
+}
+
+/// Single point of native token balance as reconstructed from transaction history.
+/// There is at most one point per block.
+#[derive(Debug, Clone)]
+pub struct EvmNativeBalancePoint {
+    /// Block number
+    pub block_number: u64,
+
+    /// Timestamp of the block
+    pub timestamp: u64,
+
+    /// Total value received within this block in Wei
+    pub value_in: U256,
+
+    /// Total value sent out within this block in Wei
+    pub value_out: U256,
+
+    /// Total gas fee paid within this block in Wei
+    pub fee: U256,
+
+    /// Balance after all transactions within this block has been applied in Wei
+    pub balance: U256,
+}
+
+/// Native token balance over time as reconstructed from normal and internal
+/// transactions of an address.
+#[derive(Debug, Clone)]
+pub struct EvmNativeBalanceHistory {
+    /// Balance points ordered by block number ascendingly
+    pub points: Vec<EvmNativeBalancePoint>,
+
+    /// Final balance as reconstructed in Wei
+    pub reconstructed_balance: U256,
+
+    /// Whether the reconstructed balance went below zero at some point.
+    /// In which case, the balance is clamped at zero. This is a sign of missing
+    /// history e.g. address has more than 10,000 transactions, or receives
+    /// native token by other means i.e. block rewards.
+    pub is_underflow: bool,
+
+    /// Balance as reported by API used to cross-check against the reconstructed
+    /// balance in Wei. `None` if it has not been cross-checked.
+    pub reported_balance: Option<U256>,
+}
+
+impl EvmNativeBalanceHistory {
+    /// Whether the reconstructed balance doesn't agree with the reported
+    /// balance. Always `false` if it has not been cross-checked.
+    pub fn is_discrepant(&self) -> bool {
+        match self.reported_balance {
+            Some(reported) => self.is_underflow || reported != self.reconstructed_balance,
+            None => false,
+        }
+    }
+}
+
+/// Single point of ERC-20/BEP-20 token balance as reconstructed from transfer
+/// events. There is at most one point per block.
+#[derive(Debug, Clone)]
+pub struct EvmErc20HoldingPoint {
+    /// Block number
+    pub block_number: u64,
+
+    /// Timestamp of the block
+    pub timestamp: u64,
+
+    /// Raw balance after all transfers within this block has been applied
+    pub balance: U256,
+
+    /// Balance scaled by token's decimal
+    pub balance_f: f64,
+}
+
+/// Holding of a single ERC-20/BEP-20 token as reconstructed from transfer events.
+#[derive(Debug, Clone)]
+pub struct EvmErc20Holding {
+    /// Token contract address
+    pub contract_address: String,
+
+    /// Token name
+    pub token_name: String,
+
+    /// Token symbol
+    pub token_symbol: String,
+
+    /// Token decimal
+    pub token_decimal: u8,
+
+    /// Total raw amount received
+    pub total_in: U256,
+
+    /// Total raw amount sent out
+    pub total_out: U256,
+
+    /// Raw balance
+    pub balance: U256,
+
+    /// Balance scaled by token's decimal
+    pub balance_f: f64,
+
+    /// Whether the reconstructed balance went below zero at some point.
+    /// In which case, the balance is clamped at zero. This is a sign that
+    /// token changes balance without emitting transfer events e.g. rebasing token.
+    pub is_underflow: bool,
+
+    /// Balance over time ordered by block number ascendingly
+    pub points: Vec<EvmErc20HoldingPoint>,
+}
+
+/// Snapshot of ERC-20/BEP-20 token holdings of an address as of a block.
+#[derive(Debug, Clone)]
+pub struct EvmErc20HoldingsSnapshot {
+    /// Block number which holdings are reconstructed as of (inclusive).
+    /// `None` for as of the latest transfer event.
+    pub block_number: Option<u64>,
+
+    /// Holdings ordered by the time each token is first transferred
+    pub holdings: Vec<EvmErc20Holding>,
+}
+
+/// Response of `proxy` module which follows JSON-RPC, but API itself can
+/// respond with its own `status`, and `message` fields instead e.g. invalid
+/// API key.
+#[derive(Debug, serde::Deserialize)]
+pub struct EvmProxyResponse {
+    #[serde(default)]
+    pub jsonrpc: Option<String>,
+
+    #[serde(default)]
+    pub id: Option<serde_json::Value>,
+
+    /// Result of JSON-RPC call, or error message in text for API's own error.
+    /// It is `null` for not found item e.g. block, or transaction.
+    #[serde(default)]
+    pub result: Option<serde_json::Value>,
+
+    /// Error of JSON-RPC call
+    #[serde(default)]
+    pub error: Option<EvmProxyRpcError>,
+
+    /// Only for API's own error
+    #[serde(default)]
+    pub status: Option<String>,
+
+    /// Only for API's own error
+    #[serde(default)]
+    pub message: Option<String>,
+}
+
+/// Error object of JSON-RPC call
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+pub struct EvmProxyRpcError {
+    pub code: i64,
+    pub message: String,
+
+    #[serde(default)]
+    pub data: Option<serde_json::Value>,
+}
+
+/// Block parameter of JSON-RPC call
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvmBlockParameter {
+    /// Block number
+    Number(u64),
+
+    /// The latest mined block
+    Latest,
+
+    /// The earliest, or genesis block
+    Earliest,
+
+    /// Pending state i.e. block being mined
+    Pending,
+}
+
+impl EvmBlockParameter {
+    /// Value as used in `tag` query parameter e.g. `0x10d4f`, or `latest`
+    pub fn as_tag(&self) -> String {
+        match self {
+            EvmBlockParameter::Number(number) => format!("{:#x}", number),
+            EvmBlockParameter::Latest => "latest".to_owned(),
+            EvmBlockParameter::Earliest => "earliest".to_owned(),
+            EvmBlockParameter::Pending => "pending".to_owned(),
+        }
+    }
+}
+
+/// Block as returned from JSON-RPC call. `T` is either transaction hash in
+/// `String`, or `EvmRpcTransaction` for full transactions.
+///
+/// Fields which are `Option` are either `null` for pending block, or not
+/// available on every chain, or before a certain hard fork.
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvmRpcBlock<T> {
+    /// Base fee per gas in Wei, since London hard fork
+    #[serde(default, deserialize_with = "de_opt_hex_string_to_U256")]
+    pub base_fee_per_gas: Option<U256>,
+
+    #[serde(deserialize_with = "de_hex_string_to_U256")]
+    pub difficulty: U256,
+
+    pub extra_data: String,
+
+    #[serde(deserialize_with = "de_hex_string_to_U256")]
+    pub gas_limit: U256,
+
+    #[serde(deserialize_with = "de_hex_string_to_U256")]
+    pub gas_used: U256,
+
+    #[serde(default)]
+    pub hash: Option<String>,
+
+    #[serde(default)]
+    pub logs_bloom: Option<String>,
+
+    pub miner: String,
+
+    #[serde(default)]
+    pub mix_hash: Option<String>,
+
+    #[serde(default)]
+    pub nonce: Option<String>,
+
+    #[serde(default, deserialize_with = "de_opt_hex_string_to_u64")]
+    pub number: Option<u64>,
+
+    pub parent_hash: String,
+
+    pub receipts_root: String,
+
+    pub sha3_uncles: String,
+
+    /// Size of block in bytes
+    #[serde(deserialize_with = "de_hex_string_to_u64")]
+    pub size: u64,
+
+    pub state_root: String,
+
+    /// Timestamp in seconds since Unix epoch
+    #[serde(deserialize_with = "de_hex_string_to_u64")]
+    pub timestamp: u64,
+
+    #[serde(default, deserialize_with = "de_opt_hex_string_to_U256")]
+    pub total_difficulty: Option<U256>,
+
+    pub transactions: Vec<T>,
+
+    pub transactions_root: String,
+
+    #[serde(default)]
+    pub uncles: Vec<String>,
+}
+
+/// Transaction as returned from JSON-RPC call
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvmRpcTransaction {
+    /// `None` for pending transaction
+    #[serde(default)]
+    pub block_hash: Option<String>,
+
+    /// `None` for pending transaction
+    #[serde(default, deserialize_with = "de_opt_hex_string_to_u64")]
+    pub block_number: Option<u64>,
+
+    pub from: String,
+
+    #[serde(deserialize_with = "de_hex_string_to_U256")]
+    pub gas: U256,
+
+    /// Gas price in Wei. For EIP-1559 transaction, it is effective gas price
+    /// once mined.
+    #[serde(default, deserialize_with = "de_opt_hex_string_to_U256")]
+    pub gas_price: Option<U256>,
+
+    /// Only for EIP-1559 transaction
+    #[serde(default, deserialize_with = "de_opt_hex_string_to_U256")]
+    pub max_fee_per_gas: Option<U256>,
+
+    /// Only for EIP-1559 transaction
+    #[serde(default, deserialize_with = "de_opt_hex_string_to_U256")]
+    pub max_priority_fee_per_gas: Option<U256>,
+
+    pub hash: String,
+
+    pub input: String,
+
+    #[serde(deserialize_with = "de_hex_string_to_u64")]
+    pub nonce: u64,
+
+    /// `None` for contract creation
+    #[serde(default)]
+    pub to: Option<String>,
+
+    /// `None` for pending transaction
+    #[serde(default, deserialize_with = "de_opt_hex_string_to_u64")]
+    pub transaction_index: Option<u64>,
+
+    #[serde(deserialize_with = "de_hex_string_to_U256")]
+    pub value: U256,
+
+    /// Transaction type i.e. 0 for legacy, 1 for EIP-2930, and 2 for EIP-1559.
+    /// `None` for chain which doesn't support typed transaction.
+    #[serde(default, rename = "type", deserialize_with = "de_opt_hex_string_to_u64")]
+    pub r#type: Option<u64>,
+
+    #[serde(default, deserialize_with = "de_opt_hex_string_to_u64")]
+    pub chain_id: Option<u64>,
+
+    /// Access list of EIP-2930, and EIP-1559 transaction kept as-is
+    #[serde(default)]
+    pub access_list: Option<serde_json::Value>,
+
+    #[serde(default)]
+    pub v: Option<String>,
+
+    #[serde(default)]
+    pub r: Option<String>,
+
+    #[serde(default)]
+    pub s: Option<String>,
+}
+
+/// Log as emitted by transaction in JSON-RPC result
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvmRpcLog {
+    /// Address of contract emitting the log
+    pub address: String,
+
+    /// Indexed topics; the first one is event's topic unless it is anonymous
+    pub topics: Vec<String>,
+
+    /// Non-indexed data in hexadecimal string
+    pub data: String,
+
+    #[serde(default)]
+    pub block_hash: Option<String>,
+
+    #[serde(default, deserialize_with = "de_opt_hex_string_to_u64")]
+    pub block_number: Option<u64>,
+
+    #[serde(default)]
+    pub transaction_hash: Option<String>,
+
+    #[serde(default, deserialize_with = "de_opt_hex_string_to_u64")]
+    pub transaction_index: Option<u64>,
+
+    #[serde(default, deserialize_with = "de_opt_hex_string_to_u64")]
+    pub log_index: Option<u64>,
+
+    /// Whether the log was removed due to chain reorganization
+    #[serde(default)]
+    pub removed: bool,
+}
+
+/// Transaction receipt as returned from JSON-RPC call
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvmRpcTransactionReceipt {
+    pub block_hash: String,
+
+    #[serde(deserialize_with = "de_hex_string_to_u64")]
+    pub block_number: u64,
+
+    /// Address of created contract, `None` if it's not contract creation
+    #[serde(default)]
+    pub contract_address: Option<String>,
+
+    #[serde(deserialize_with = "de_hex_string_to_U256")]
+    pub cumulative_gas_used: U256,
+
+    /// Actual gas price paid per gas in Wei. `None` for chain which doesn't
+    /// support it yet.
+    #[serde(default, deserialize_with = "de_opt_hex_string_to_U256")]
+    pub effective_gas_price: Option<U256>,
+
+    pub from: String,
+
+    #[serde(deserialize_with = "de_hex_string_to_U256")]
+    pub gas_used: U256,
+
+    pub logs: Vec<EvmRpcLog>,
+
+    #[serde(default)]
+    pub logs_bloom: Option<String>,
+
+    /// 1 for success, or 0 for failure. `None` for transaction before
+    /// Byzantium hard fork which has `root` instead.
+    #[serde(default, deserialize_with = "de_opt_hex_string_to_u64")]
+    pub status: Option<u64>,
+
+    /// Post-transaction state root, only for transaction before Byzantium hard fork
+    #[serde(default)]
+    pub root: Option<String>,
+
+    /// `None` for contract creation
+    #[serde(default)]
+    pub to: Option<String>,
+
+    pub transaction_hash: String,
+
+    #[serde(deserialize_with = "de_hex_string_to_u64")]
+    pub transaction_index: u64,
+
+    /// Transaction type i.e. 0 for legacy, 1 for EIP-2930, and 2 for EIP-1559
+    #[serde(default, rename = "type", deserialize_with = "de_opt_hex_string_to_u64")]
+    pub r#type: Option<u64>,
+}
+
+impl EvmRpcTransactionReceipt {
+    /// Whether the transaction succeeded. `None` if it is unknown i.e. before
+    /// Byzantium hard fork.
+    pub fn is_success(&self) -> Option<bool> {
+        self.status.map(|status| status == 1)
+    }
+
+    /// Transaction fee paid in Wei. `None` if effective gas price is not available.
+    pub fn fee(&self) -> Option<U256> {
+        self.effective_gas_price.map(|price| price.saturating_mul(self.gas_used))
+    }
+}
+
+/// Transaction execution status response
+#[derive(Debug, serde::Deserialize)]
+pub struct EvmTransactionExecutionStatusResponse {
+    pub status: String,
+    pub message: String,
+    pub result: EvmTransactionExecutionStatusResult,
+}
+
+/// Structure holding variant response for field `result` of Transactions's
+/// getting execution status API.
+#[derive(Debug, serde::Deserialize)]
+#[serde(untagged)]
+pub enum EvmTransactionExecutionStatusResult {
+    Success(EvmTransactionExecutionStatus),
+    Failed(String),
+}
+
+/// Execution status of a transaction
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvmTransactionExecutionStatus {
+    /// Whether there was an error during execution e.g. reverted
+    #[serde(deserialize_with = "de_string_to_bool")]
+    pub is_error: bool,
+
+    /// Description of the error, empty if there is no error
+    pub err_description: String,
+}
+
+/// Transaction receipt status response
+#[derive(Debug, serde::Deserialize)]
+pub struct EvmTransactionReceiptStatusResponse {
+    pub status: String,
+    pub message: String,
+    pub result: EvmTransactionReceiptStatusResult,
+}
+
+/// Structure holding variant response for field `result` of Transactions's
+/// getting receipt status API.
+#[derive(Debug, serde::Deserialize)]
+#[serde(untagged)]
+pub enum EvmTransactionReceiptStatusResult {
+    Success(EvmTransactionReceiptStatus),
+    Failed(String),
+}
+
+/// Receipt status of a transaction
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+pub struct EvmTransactionReceiptStatus {
+    /// `1` for success, `0` for failure, and empty before Byzantium hard fork
+    pub status: String,
+}
+
+impl EvmTransactionReceiptStatus {
+    /// Whether the transaction succeeded. `None` if it is unknown i.e. before
+    /// Byzantium hard fork.
+    pub fn is_success(&self) -> Option<bool> {
+        match self.status.as_str() {
+            "1" => Some(true),
+            "0" => Some(false),
+            _ => None,
+        }
+    }
+}
+
+/// Execution, and receipt status of a transaction
+#[derive(Debug, Clone, PartialEq)]
+pub struct EvmTransactionStatus {
+    /// Transaction hash
+    pub tx_hash: String,
+
+    /// Execution status
+    pub execution: EvmTransactionExecutionStatus,
+
+    /// Whether the transaction succeeded as seen in its receipt. `None` if it
+    /// is unknown i.e. before Byzantium hard fork.
+    pub receipt_status: Option<bool>,
+}
+
+/// Block reward response
+#[derive(Debug, serde::Deserialize)]
+pub struct EvmBlockRewardResponse {
+    pub status: String,
+    pub message: String,
+    pub result: EvmBlockRewardResult,
+}
+
+/// Structure holding variant response for field `result` of Blocks's getting
+/// block reward API.
+#[derive(Debug, serde::Deserialize)]
+#[serde(untagged)]
+pub enum EvmBlockRewardResult {
+    Success(EvmBlockReward),
+    Failed(String),
+}
+
+/// Reward of a block
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvmBlockReward {
+    #[serde(deserialize_with = "de_string_to_numeric")]
+    pub block_number: u64,
+
+    #[serde(rename = "timeStamp", deserialize_with = "de_string_to_numeric")]
+    pub timestamp: u64,
+
+    /// Address which mined, or validated the block
+    pub block_miner: String,
+
+    /// Reward in Wei
+    #[serde(deserialize_with = "de_string_to_U256")]
+    pub block_reward: U256,
+
+    /// Uncles included in the block, always empty on proof-of-stake chain
+    #[serde(default)]
+    pub uncles: Vec<EvmBlockUncleReward>,
+
+    /// Reward for including uncles in Wei
+    #[serde(deserialize_with = "de_string_to_U256")]
+    pub uncle_inclusion_reward: U256,
+}
+
+/// Reward of an uncle block
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvmBlockUncleReward {
+    pub miner: String,
+
+    #[serde(deserialize_with = "de_string_to_numeric")]
+    pub uncle_position: u64,
+
+    /// Reward in Wei
+    #[serde(rename = "blockreward", deserialize_with = "de_string_to_U256")]
+    pub block_reward: U256,
+}
+
+/// Block countdown response
+#[derive(Debug, serde::Deserialize)]
+pub struct EvmBlockCountdownResponse {
+    pub status: String,
+    pub message: String,
+    pub result: EvmBlockCountdownResult,
+}
+
+/// Structure holding variant response for field `result` of Blocks's getting
+/// block countdown API.
+#[derive(Debug, serde::Deserialize)]
+#[serde(untagged)]
+pub enum EvmBlockCountdownResult {
+    Success(EvmBlockCountdown),
+    Failed(String),
+}
+
+/// Estimated time remaining until a future block is mined
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct EvmBlockCountdown {
+    #[serde(deserialize_with = "de_string_to_numeric")]
+    pub current_block: u64,
+
+    #[serde(deserialize_with = "de_string_to_numeric")]
+    pub countdown_block: u64,
+
+    #[serde(deserialize_with = "de_string_to_numeric")]
+    pub remaining_block: u64,
+
+    /// Estimated time in seconds
+    #[serde(deserialize_with = "de_string_to_numeric")]
+    pub estimate_time_in_sec: f64,
+}
+
+/// Block number response
+#[derive(Debug, serde::Deserialize)]
+pub struct EvmBlockNumberResponse {
+    pub status: String,
+    pub message: String,
+
+    /// Block number in decimal, or error message
+    pub result: String,
+}
+
+/// Which block to take when there is no block at exactly the timestamp
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvmBlockClosest {
+    /// The last block before the timestamp
+    Before,
+
+    /// The first block after the timestamp
+    After,
+}
+
+impl EvmBlockClosest {
+    /// Value as used in API's `closest` parameter
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EvmBlockClosest::Before => "before",
+            EvmBlockClosest::After => "after",
+        }
+    }
+}
+
+/// Operator combining two topic filters of logs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvmLogTopicOperator {
+    And,
+    Or,
+}
+
+impl EvmLogTopicOperator {
+    /// Value of `topicX_Y_opr` parameter as accepted by API
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EvmLogTopicOperator::And => "and",
+            EvmLogTopicOperator::Or => "or",
+        }
+    }
+}
+
+/// Filter of event logs
+#[derive(Debug, Clone)]
+pub struct EvmLogFilter {
+    /// Contract address which emitted logs, `None` for any address
+    pub address: Option<String>,
+
+    /// Start block, inclusive
+    pub from_block: u64,
+
+    /// End block, inclusive. `None` for the latest block.
+    pub to_block: Option<u64>,
+
+    /// Filter of `topic0` to `topic3` in hex, `None` for any topic
+    pub topics: [Option<String>; 4],
+
+    /// Operators between pair of topics in form of `(x, y, operator)` with
+    /// `x < y`, both topics need to be set. API defaults to `And` if not set.
+    pub topic_operators: Vec<(usize, usize, EvmLogTopicOperator)>,
+}
+
+impl EvmLogFilter {
+    /// Create a new filter without any topic.
+    ///
+    /// # Arguments
+    /// * `address` - optional contract address which emitted logs
+    /// * `from_block` - start block, inclusive
+    /// * `to_block` - optional end block, inclusive
+    pub fn create(address: Option<&str>, from_block: u64, to_block: Option<u64>) -> EvmLogFilter {
+        EvmLogFilter {
+            address: address.map(|a| a.to_owned()),
+            from_block,
+            to_block,
+            topics: [None, None, None, None],
+            topic_operators: Vec::new(),
+        }
+    }
+}
+
+/// Event logs response
+#[derive(Debug, serde::Deserialize)]
+pub struct EvmLogsResponse {
+    pub status: String,
+    pub message: String,
+    pub result: EvmLogsResult,
+}
+
+/// Structure holding variant response for field `result` of Logs's getting
+/// logs API.
+#[derive(Debug, serde::Deserialize)]
+#[serde(untagged)]
+pub enum EvmLogsResult {
+    Success(Vec<EvmLog>),
+    Failed(String),
+}
+
+/// Event log as returned from Logs API. Numeric fields are in hex in which
+/// `0x` alone means zero.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvmLog {
+    pub address: String,
+    pub topics: Vec<String>,
+    pub data: String,
+
+    #[serde(deserialize_with = "de_hex_string_to_u64")]
+    pub block_number: u64,
+
+    #[serde(default)]
+    pub block_hash: Option<String>,
+
+    #[serde(rename = "timeStamp", deserialize_with = "de_hex_string_to_u64")]
+    pub timestamp: u64,
+
+    #[serde(deserialize_with = "de_hex_string_to_U256")]
+    pub gas_price: U256,
+
+    /// Gas used by the transaction
+    #[serde(deserialize_with = "de_hex_string_to_U256")]
+    pub gas_used: U256,
+
+    #[serde(deserialize_with = "de_hex_string_to_u64")]
+    pub log_index: u64,
+
+    pub transaction_hash: String,
+
+    #[serde(deserialize_with = "de_hex_string_to_u64")]
+    pub transaction_index: u64,
+}
+
+/// Gas oracle response in which `T` is structure specific to chain
+#[derive(Debug, serde::Deserialize)]
+pub struct EvmGasOracleResponse<T> {
+    pub status: String,
+    pub message: String,
+    pub result: EvmGasOracleResult<T>,
+}
+
+/// Structure holding variant response for field `result` of Gas Tracker's
+/// getting gas oracle API.
+#[derive(Debug, serde::Deserialize)]
+#[serde(untagged)]
+pub enum EvmGasOracleResult<T> {
+    Success(T),
+    Failed(String),
+}
+
+/// Gas oracle which is consistent across chains. Gas prices are in Gwei.
+///
+/// Each chain returns slightly different fields, they are deserialized into
+/// structure specific to the chain e.g. `EvmGasOracle_BSC` then mapped into
+/// this structure internally.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EvmGasOracle {
+    pub last_block: u64,
+    pub safe_gas_price: f64,
+    pub propose_gas_price: f64,
+    pub fast_gas_price: f64,
+
+    /// Base fee of the next block in Gwei, `None` if not returned by chain
+    pub suggest_base_fee: Option<f64>,
+
+    /// Ratio of gas used to gas limit of recent blocks, empty if not returned
+    /// by chain
+    pub gas_used_ratio: Vec<f64>,
+
+    /// Price of native token in USD, `None` if not returned by chain
+    pub usd_price: Option<f64>,
+}
+
+/// Actual structure holding a success response for Gas Tracker's getting gas
+/// oracle on Ethereum chain only.
+///
+/// NOTE: Normally users won't directly use this. It is mapped into
+/// `EvmGasOracle` internally.
+#[derive(Debug, serde::Deserialize)]
+#[allow(non_camel_case_types)]
+pub struct EvmGasOracle_Ethereum {
+    #[serde(rename = "LastBlock", deserialize_with = "de_string_to_numeric")]
+    pub last_block: u64,
+
+    #[serde(rename = "SafeGasPrice", deserialize_with = "de_string_to_numeric")]
+    pub safe_gas_price: f64,
+
+    #[serde(rename = "ProposeGasPrice", deserialize_with = "de_string_to_numeric")]
+    pub propose_gas_price: f64,
+
+    #[serde(rename = "FastGasPrice", deserialize_with = "de_string_to_numeric")]
+    pub fast_gas_price: f64,
+
+    #[serde(rename = "suggestBaseFee", deserialize_with = "de_string_to_numeric")]
+    pub suggest_base_fee: f64,
+
+    #[serde(rename = "gasUsedRatio", deserialize_with = "de_comma_separated_string_to_vec_f64")]
+    pub gas_used_ratio: Vec<f64>,
+}
+
+/// Actual structure holding a success response for Gas Tracker's getting gas
+/// oracle on BSC chain only. It has no base fee, and gas used ratio, but has
+/// price of BNB in USD.
+///
+/// NOTE: Normally users won't directly use this. It is mapped into
+/// `EvmGasOracle` internally.
+#[derive(Debug, serde::Deserialize)]
+#[allow(non_camel_case_types)]
+pub struct EvmGasOracle_BSC {
+    #[serde(rename = "LastBlock", deserialize_with = "de_string_to_numeric")]
+    pub last_block: u64,
+
+    #[serde(rename = "SafeGasPrice", deserialize_with = "de_string_to_numeric")]
+    pub safe_gas_price: f64,
+
+    #[serde(rename = "ProposeGasPrice", deserialize_with = "de_string_to_numeric")]
+    pub propose_gas_price: f64,
+
+    #[serde(rename = "FastGasPrice", deserialize_with = "de_string_to_numeric")]
+    pub fast_gas_price: f64,
+
+    #[serde(rename = "UsdPrice", deserialize_with = "de_string_to_numeric")]
+    pub usd_price: f64,
+}
+
+/// Actual structure holding a success response for Gas Tracker's getting gas
+/// oracle on Polygon chain only. It has price of MATIC in USD in addition to
+/// fields as of Ethereum.
+///
+/// NOTE: Normally users won't directly use this. It is mapped into
+/// `EvmGasOracle` internally.
+#[derive(Debug, serde::Deserialize)]
+#[allow(non_camel_case_types)]
+pub struct EvmGasOracle_Polygon {
+    #[serde(rename = "LastBlock", deserialize_with = "de_string_to_numeric")]
+    pub last_block: u64,
+
+    #[serde(rename = "SafeGasPrice", deserialize_with = "de_string_to_numeric")]
+    pub safe_gas_price: f64,
+
+    #[serde(rename = "ProposeGasPrice", deserialize_with = "de_string_to_numeric")]
+    pub propose_gas_price: f64,
+
+    #[serde(rename = "FastGasPrice", deserialize_with = "de_string_to_numeric")]
+    pub fast_gas_price: f64,
+
+    #[serde(rename = "suggestBaseFee", deserialize_with = "de_string_to_numeric")]
+    pub suggest_base_fee: f64,
+
+    #[serde(rename = "gasUsedRatio", deserialize_with = "de_comma_separated_string_to_vec_f64")]
+    pub gas_used_ratio: Vec<f64>,
+
+    #[serde(rename = "UsdPrice", deserialize_with = "de_string_to_numeric")]
+    pub usd_price: f64,
+}
+
+impl From<EvmGasOracle_Ethereum> for EvmGasOracle {
+    fn from(oracle: EvmGasOracle_Ethereum) -> Self {
+        EvmGasOracle {
+            last_block: oracle.last_block,
+            safe_gas_price: oracle.safe_gas_price,
+            propose_gas_price: oracle.propose_gas_price,
+            fast_gas_price: oracle.fast_gas_price,
+            suggest_base_fee: Some(oracle.suggest_base_fee),
+            gas_used_ratio: oracle.gas_used_ratio,
+            usd_price: None,
+        }
+    }
+}
+
+impl From<EvmGasOracle_BSC> for EvmGasOracle {
+    fn from(oracle: EvmGasOracle_BSC) -> Self {
+        EvmGasOracle {
+            last_block: oracle.last_block,
+            safe_gas_price: oracle.safe_gas_price,
+            propose_gas_price: oracle.propose_gas_price,
+            fast_gas_price: oracle.fast_gas_price,
+            suggest_base_fee: None,
+            gas_used_ratio: Vec::new(),
+            usd_price: Some(oracle.usd_price),
+        }
+    }
+}
+
+impl From<EvmGasOracle_Polygon> for EvmGasOracle {
+    fn from(oracle: EvmGasOracle_Polygon) -> Self {
+        EvmGasOracle {
+            last_block: oracle.last_block,
+            safe_gas_price: oracle.safe_gas_price,
+            propose_gas_price: oracle.propose_gas_price,
+            fast_gas_price: oracle.fast_gas_price,
+            suggest_base_fee: Some(oracle.suggest_base_fee),
+            gas_used_ratio: oracle.gas_used_ratio,
+            usd_price: Some(oracle.usd_price),
+        }
+    }
+}
+
+/// Confirmation time estimate response
+#[derive(Debug, serde::Deserialize)]
+pub struct EvmGasEstimateResponse {
+    pub status: String,
+    pub message: String,
+
+    /// Estimated time in seconds, or error message
+    pub result: String,
 }